@@ -0,0 +1,31 @@
+// Times generation of sine, rectangular and triangular signals through the public API
+// Run with `cargo bench` and `cargo bench --features simd` to compare the scalar loops with the ones of `simd.rs`
+
+use std::hint::black_box;
+use std::time::Instant;
+
+use signalum::SignalProcessor;
+
+const SAMPLING_FREQUENCY: f64 = 48000.0;
+/// Length of every signal in s
+const DURATION: f64 = 20.0;
+const RUNS: u32 = 10;
+
+fn main() {
+	let mut processor = SignalProcessor::new(SAMPLING_FREQUENCY, 0.0).unwrap_or_else(|_| panic!("Invalid sampling"));
+	let signals = [
+		("sine", processor.add_sine(440.0, DURATION, 0.0, 1.0, 0.3)),
+		("rectangular", processor.add_symmetric_rectangular(440.0, DURATION, 0.0, 1.0, 0.3)),
+		("triangular", processor.add_triangular(440.0, DURATION, 0.0, 1.0, 0.3)),
+	];
+	for (name, id) in signals {
+		let id = id.unwrap_or_else(|_| panic!("Could not add {}", name));
+		// signals evaluated alone aren't cached, so every run computes all values again
+		let started = Instant::now();
+		for _ in 0..RUNS {
+			black_box(processor.get_signal_by_id(id).unwrap_or_else(|_| panic!("No signal {}", id)));
+		}
+		let seconds_per_sample = started.elapsed().as_secs_f64() / RUNS as f64 / (DURATION * SAMPLING_FREQUENCY);
+		println!("{:<12} {:6.2} ns per sample", name, seconds_per_sample * 1e9);
+	}
+}
\ No newline at end of file
@@ -0,0 +1,162 @@
+#[cfg(feature = "wasm")]
+use wasm_bindgen::prelude::*;
+
+use crate::filters::Filter;
+use crate::transforms;
+
+#[cfg_attr(feature = "wasm", wasm_bindgen)]
+pub struct LevelMeters {
+	/// Largest absolute sample value in dBFS
+	pub peak_db: f64,
+	/// RMS level in dBFS
+	pub rms_db: f64,
+	/// Gated integrated loudness following ITU-R BS.1770 in LUFS, treating the signal as a single channel
+	pub loudness_lufs: f64,
+}
+
+#[cfg_attr(feature = "wasm", wasm_bindgen)]
+pub struct BandLevel {
+	/// Nominal center frequency in Hz
+	pub center: f64,
+	/// Lower edge in Hz
+	pub lower: f64,
+	/// Upper edge in Hz
+	pub upper: f64,
+	/// RMS level of the band in dBFS
+	pub level_db: f64,
+}
+
+/// Levels of fractional octave bands with base 2 center frequencies 1000 * 2^(k / `bands_per_octave`)
+/// Bands from 16 Hz up to the last one fully below Nyquist frequency, power is summed from the FFT of `samples`
+pub fn band_levels(samples: &[f64], sampling_frequency: f64, bands_per_octave: u32) -> Vec<BandLevel> {
+	let band_power = one_sided_power_spectrum(samples);
+	let bin_width = sampling_frequency / ((band_power.len() - 1) * 2) as f64;
+	let bands_per_octave = bands_per_octave.max(1) as f64;
+	let half_band_ratio = 2.0_f64.powf(0.5 / bands_per_octave);
+	let first_band = (bands_per_octave * (16.0_f64 / 1000.0).log2()).round() as i32;
+	let mut levels = Vec::new();
+	for band in first_band.. {
+		let center = 1000.0 * 2.0_f64.powf(band as f64 / bands_per_octave);
+		let lower = center / half_band_ratio;
+		let upper = center * half_band_ratio;
+		if upper > sampling_frequency / 2.0 {
+			break;
+		}
+		let power: f64 = band_power.iter().enumerate().filter(|(bin, _)| {
+			let frequency = *bin as f64 * bin_width;
+			return frequency >= lower && frequency < upper;
+		}).map(|(_, power)| power).sum();
+		levels.push(BandLevel {
+			center,
+			lower,
+			upper,
+			level_db: 10.0 * power.log10(),
+		});
+	}
+	return levels;
+}
+
+/// Linear gain of frequency weighting `curve` ("A", "C" or "Z" for none) at `frequency` Hz, as defined in IEC 61672
+pub fn weighting_gain(curve: &str, frequency: f64) -> f64 {
+	let squared = frequency * frequency;
+	let (pole_1, pole_2, pole_3, pole_4) = (20.6_f64.powi(2), 107.7_f64.powi(2), 737.9_f64.powi(2), 12194.0_f64.powi(2));
+	return match curve {
+		// constant factors make the gain exactly 0 dB at 1 kHz
+		"A" => pole_4 * squared * squared / ((squared + pole_1) * ((squared + pole_2) * (squared + pole_3)).sqrt() * (squared + pole_4)) * 10.0_f64.powf(2.0 / 20.0),
+		"C" => pole_4 * squared / ((squared + pole_1) * (squared + pole_4)) * 10.0_f64.powf(0.062 / 20.0),
+		"Z" => 1.0,
+		_ => panic!("Unknown weighting curve {}", curve),
+	};
+}
+
+/// Whether `weighting_gain` accepts `curve`
+pub fn is_weighting(curve: &str) -> bool {
+	return matches!(curve, "A" | "C" | "Z");
+}
+
+/// Filters `samples` with weighting `curve` by scaling their spectrum, without any phase shift
+pub fn apply_weighting(samples: &[f64], sampling_frequency: f64, curve: &str) -> Vec<f64> {
+	let mut spectrum = transforms::fft_real(samples);
+	let padded_length = spectrum.len();
+	for (bin, value) in spectrum.iter_mut().enumerate() {
+		// upper half of the bins holds negative frequencies, which get the same gain
+		let frequency = transforms::bin_to_frequency(bin.min(padded_length - bin) as f64, padded_length, sampling_frequency);
+		*value = value.scale(weighting_gain(curve, frequency));
+	}
+	transforms::fft_in_place(&mut spectrum, true);
+	return spectrum.into_iter().take(samples.len()).map(|value| value.re).collect();
+}
+
+/// RMS level in dBFS of `samples` weighted with `curve`
+pub fn weighted_level_db(samples: &[f64], sampling_frequency: f64, curve: &str) -> f64 {
+	let power = one_sided_power_spectrum(samples);
+	let bin_width = sampling_frequency / ((power.len() - 1) * 2) as f64;
+	let weighted_power: f64 = power.iter().enumerate().map(|(bin, power)| power * weighting_gain(curve, bin as f64 * bin_width).powi(2)).sum();
+	return 10.0 * weighted_power.log10();
+}
+
+/// Peak and RMS level in dBFS and integrated loudness of `samples`, all computed in a single pass over time domain values
+pub fn level_meters(samples: &[f64], sampling_frequency: f64) -> LevelMeters {
+	let peak = samples.iter().fold(0.0, |peak: f64, sample| peak.max(sample.abs()));
+	let mean_square = samples.iter().map(|sample| sample * sample).sum::<f64>() / samples.len().max(1) as f64;
+	return LevelMeters {
+		peak_db: 20.0 * peak.log10(),
+		rms_db: 10.0 * mean_square.log10(),
+		loudness_lufs: integrated_loudness(samples, sampling_frequency),
+	};
+}
+
+/// Integrated loudness in LUFS of K-weighted `samples` over 400 ms blocks overlapping by 75%
+/// Blocks quieter than -70 LUFS and then 10 LU below the average of the rest are ignored
+fn integrated_loudness(samples: &[f64], sampling_frequency: f64) -> f64 {
+	let weighted = k_weighting(sampling_frequency).iter().fold(samples.to_vec(), |signal, stage| stage.apply(&signal));
+	let block_length = ((0.4 * sampling_frequency).round() as usize).max(1);
+	let hop = (block_length / 4).max(1);
+	let mut block_powers: Vec<f64> = Vec::new();
+	let mut block_start = 0;
+	while block_start + block_length <= weighted.len() {
+		block_powers.push(weighted[block_start..block_start + block_length].iter().map(|sample| sample * sample).sum::<f64>() / block_length as f64);
+		block_start += hop;
+	}
+	let loudness = |power: f64| -0.691 + 10.0 * power.log10();
+	let gated_average = |threshold: f64| {
+		let loud_blocks: Vec<f64> = block_powers.iter().copied().filter(|power| loudness(*power) > threshold).collect();
+		return loud_blocks.iter().sum::<f64>() / loud_blocks.len() as f64;
+	};
+	let relative_threshold = loudness(gated_average(-70.0)) - 10.0;
+	return loudness(gated_average(relative_threshold));
+}
+
+/// Two stages of the K-weighting filter - a high shelf modelling the head and a highpass, recalculated for `sampling_frequency`
+fn k_weighting(sampling_frequency: f64) -> [Filter; 2] {
+	let shelf_k = (std::f64::consts::PI * 1681.974450955533 / sampling_frequency).tan();
+	let shelf_q = 0.7071752369554196;
+	let high_gain = 10.0_f64.powf(3.999843853973347 / 20.0);
+	let band_gain = high_gain.powf(0.4996667741545416);
+	let shelf_a0 = 1.0 + shelf_k / shelf_q + shelf_k * shelf_k;
+	let shelf = Filter::new(
+		vec![(high_gain + band_gain * shelf_k / shelf_q + shelf_k * shelf_k) / shelf_a0, 2.0 * (shelf_k * shelf_k - high_gain) / shelf_a0, (high_gain - band_gain * shelf_k / shelf_q + shelf_k * shelf_k) / shelf_a0],
+		vec![1.0, 2.0 * (shelf_k * shelf_k - 1.0) / shelf_a0, (1.0 - shelf_k / shelf_q + shelf_k * shelf_k) / shelf_a0],
+	);
+	let highpass_k = (std::f64::consts::PI * 38.13547087602444 / sampling_frequency).tan();
+	let highpass_q = 0.5003270373238773;
+	let highpass_a0 = 1.0 + highpass_k / highpass_q + highpass_k * highpass_k;
+	let highpass = Filter::new(
+		vec![1.0, -2.0, 1.0],
+		vec![1.0, 2.0 * (highpass_k * highpass_k - 1.0) / highpass_a0, (1.0 - highpass_k / highpass_q + highpass_k * highpass_k) / highpass_a0],
+	);
+	return [shelf, highpass];
+}
+
+/// Contribution of every bin from DC to Nyquist frequency to the mean square of `samples`
+/// Contributions of all bins sum up to the mean square, as follows from Parseval's theorem
+pub fn one_sided_power_spectrum(samples: &[f64]) -> Vec<f64> {
+	let spectrum = transforms::fft_real(samples);
+	let padded_length = spectrum.len();
+	let scale = 1.0 / (samples.len().max(1) as f64 * padded_length as f64);
+	return spectrum[..=padded_length / 2].iter().enumerate().map(|(bin, value)| {
+		// negative frequencies are folded onto positive ones, DC and Nyquist bins have no counterpart
+		let folding = if bin == 0 || bin == padded_length / 2 { 1.0 } else { 2.0 };
+		return value.norm_sqr() * scale * folding;
+	}).collect();
+}
\ No newline at end of file
@@ -0,0 +1,610 @@
+use std::f64::consts::TAU;
+
+#[cfg(feature = "wasm")]
+use wasm_bindgen::prelude::*;
+
+use crate::complex::Complex;
+use crate::transforms;
+use crate::windows;
+use crate::CoordPair;
+
+#[cfg_attr(feature = "wasm", wasm_bindgen)]
+pub struct DistributionFit {
+	/// Lower bound of the fitted uniform distribution
+	pub uniform_low: f64,
+	/// Upper bound of the fitted uniform distribution
+	pub uniform_high: f64,
+	/// Kolmogorov-Smirnov statistic of the uniform fit, between 0 and 1. Lower is better
+	pub uniform_ks: f64,
+	/// Mean of the fitted normal distribution
+	pub normal_mean: f64,
+	/// Standard deviation of the fitted normal distribution
+	pub normal_std_dev: f64,
+	/// Kolmogorov-Smirnov statistic of the normal fit, between 0 and 1. Lower is better
+	pub normal_ks: f64,
+}
+
+#[cfg_attr(feature = "wasm", wasm_bindgen)]
+pub struct FrequencyMeasurement {
+	/// Frequency in Hz from the average time between rising zero crossings, NaN with fewer than two crossings
+	pub zero_crossing: f64,
+	/// Frequency in Hz of the strongest spectral peak, NaN for signals too short to have one
+	pub fft_peak: f64,
+}
+
+#[cfg_attr(feature = "wasm", wasm_bindgen)]
+pub struct TimeInterval {
+	/// Inclusive start in s
+	pub start: f64,
+	/// Exclusive end in s
+	pub end: f64,
+}
+
+/// Differences between an original signal and its processed version, sample by sample
+#[cfg_attr(feature = "wasm", wasm_bindgen)]
+pub struct SignalComparison {
+	/// Mean squared error
+	pub mse: f64,
+	/// Largest absolute difference
+	pub max_difference: f64,
+	/// Signal-to-noise ratio in dB, power of the original over power of the difference
+	pub snr_db: f64,
+	/// Peak signal-to-noise ratio in dB, squared peak absolute value of the original over the mean squared error
+	pub psnr_db: f64,
+	/// Effective number of bits of an ideal quantizer with the same SNR for a full-scale sine, (SNR - 1.76) / 6.02
+	pub enob: f64,
+}
+
+/// Compares `processed` with `original`, both sampled at the same points. Extra samples of the longer one are ignored
+pub fn compare(original: &[f64], processed: &[f64]) -> SignalComparison {
+	let length = original.len().min(processed.len());
+	let (original, processed) = (&original[..length], &processed[..length]);
+	let differences: Vec<f64> = original.iter().zip(processed).map(|(a, b)| a - b).collect();
+	let mse = differences.iter().map(|difference| difference * difference).sum::<f64>() / length as f64;
+	let power = original.iter().map(|value| value * value).sum::<f64>() / length as f64;
+	let peak = original.iter().fold(0.0, |peak: f64, value| peak.max(value.abs()));
+	let snr_db = 10.0 * (power / mse).log10();
+	return SignalComparison {
+		mse,
+		max_difference: differences.iter().fold(0.0, |peak: f64, value| peak.max(value.abs())),
+		snr_db,
+		psnr_db: 10.0 * (peak * peak / mse).log10(),
+		enob: (snr_db - 1.76) / 6.02,
+	};
+}
+
+#[cfg_attr(feature = "wasm", wasm_bindgen)]
+pub struct SignalStatistics {
+	pub mean: f64,
+	/// Mean of absolute values
+	pub absolute_mean: f64,
+	/// Root mean square
+	pub rms: f64,
+	/// Mean squared deviation from the mean
+	pub variance: f64,
+	pub standard_deviation: f64,
+	/// Mean of squared values
+	pub average_power: f64,
+	/// Largest absolute value
+	pub peak: f64,
+	/// Difference between the largest and the smallest value
+	pub peak_to_peak: f64,
+	/// Peak over RMS
+	pub crest_factor: f64,
+}
+
+/// Basic statistics of `values`, all NaN when there are none
+pub fn statistics(values: &[f64]) -> SignalStatistics {
+	let count = values.len() as f64;
+	let mean = values.iter().sum::<f64>() / count;
+	let average_power = values.iter().map(|value| value * value).sum::<f64>() / count;
+	let variance = values.iter().map(|value| (value - mean).powi(2)).sum::<f64>() / count;
+	let (low, high) = values.iter().fold((f64::INFINITY, f64::NEG_INFINITY), |(low, high), value| (low.min(*value), high.max(*value)));
+	let peak = if values.is_empty() { f64::NAN } else { low.abs().max(high.abs()) };
+	return SignalStatistics {
+		mean,
+		absolute_mean: values.iter().map(|value| value.abs()).sum::<f64>() / count,
+		rms: average_power.sqrt(),
+		variance,
+		standard_deviation: variance.sqrt(),
+		average_power,
+		peak,
+		peak_to_peak: if values.is_empty() { f64::NAN } else { high - low },
+		crest_factor: peak / average_power.sqrt(),
+	};
+}
+
+/// Counts of values falling into evenly spaced bins
+#[cfg_attr(feature = "wasm", wasm_bindgen)]
+pub struct Histogram {
+	edges: Vec<f64>,
+	counts: Vec<u32>,
+}
+
+#[cfg_attr(feature = "wasm", wasm_bindgen)]
+impl Histogram {
+	/// Bin edges from the lowest to the highest, one more than there are bins
+	pub fn edges(&self) -> Vec<f64> {
+		return self.edges.clone();
+	}
+
+	/// Number of values in every bin
+	pub fn counts(&self) -> Vec<u32> {
+		return self.counts.clone();
+	}
+}
+
+/// Histogram of `values` in `bin_count` bins evenly covering `low` to `high`
+/// Bins include their lower edge, the last one also its upper edge. Values outside of the range aren't counted
+pub fn histogram(values: &[f64], bin_count: usize, low: f64, high: f64) -> Histogram {
+	let width = (high - low) / bin_count as f64;
+	let mut counts = vec![0; bin_count];
+	for value in values.iter().filter(|value| **value >= low && **value <= high) {
+		counts[(((value - low) / width) as usize).min(bin_count - 1)] += 1;
+	}
+	return Histogram {
+		edges: (0..=bin_count).map(|index| low + index as f64 * width).collect(),
+		counts,
+	};
+}
+
+/// β of the Kaiser window used for distortion measurements, its sidelobes leak about 120 dB less power than its main lobe holds
+const DISTORTION_KAISER_BETA: f64 = 16.0;
+/// Half width in bins of the main lobe of a sine seen through that window, with some margin
+const DISTORTION_LOBE_BINS: f64 = 6.0;
+
+/// Total harmonic distortion of `samples` taken at `sampling_frequency` with fundamental at `fundamental_freq` Hz,
+/// the RMS of the first `harmonic_count` harmonics above the fundamental over the RMS of the fundamental
+/// Harmonics above the Nyquist frequency are left out
+pub fn total_harmonic_distortion(samples: &[f64], sampling_frequency: f64, fundamental_freq: f64, harmonic_count: u32) -> f64 {
+	let spectrum = distortion_spectrum(samples, sampling_frequency);
+	let lobe = DISTORTION_LOBE_BINS * sampling_frequency / samples.len() as f64;
+	let harmonics: f64 = (2..=harmonic_count + 1).map(|order| order as f64 * fundamental_freq).filter(|frequency| *frequency + lobe < sampling_frequency / 2.0)
+		.map(|frequency| band_power(&spectrum, frequency, lobe)).sum();
+	return (harmonics / band_power(&spectrum, fundamental_freq, lobe)).sqrt();
+}
+
+/// Signal to noise and distortion ratio of `samples` taken at `sampling_frequency` in dB,
+/// power of the strongest spectral component over the power of everything else except DC
+pub fn sinad(samples: &[f64], sampling_frequency: f64) -> f64 {
+	let spectrum = distortion_spectrum(samples, sampling_frequency);
+	let lobe = DISTORTION_LOBE_BINS * sampling_frequency / samples.len() as f64;
+	let (frequencies, magnitudes) = (spectrum.frequencies(), spectrum.magnitudes());
+	let fundamental = frequencies.iter().zip(&magnitudes).filter(|(frequency, _)| **frequency > lobe)
+		.max_by(|a, b| a.1.total_cmp(b.1)).map_or(0.0, |(frequency, _)| *frequency);
+	let signal = band_power(&spectrum, fundamental, lobe);
+	let total: f64 = magnitudes.iter().map(|magnitude| magnitude * magnitude).sum();
+	return 10.0 * (signal / (total - signal - band_power(&spectrum, 0.0, lobe))).log10();
+}
+
+/// Kaiser windowed spectrum used by the distortion measurements
+fn distortion_spectrum(samples: &[f64], sampling_frequency: f64) -> transforms::Spectrum {
+	return transforms::windowed_spectrum(samples, sampling_frequency, &windows::kaiser(samples.len(), DISTORTION_KAISER_BETA));
+}
+
+/// Sum of squared magnitudes of bins of `spectrum` within `half_width` Hz of `frequency`
+fn band_power(spectrum: &transforms::Spectrum, frequency: f64, half_width: f64) -> f64 {
+	return spectrum.frequencies().into_iter().zip(spectrum.magnitudes()).filter(|(bin, _)| (bin - frequency).abs() <= half_width)
+		.map(|(_, magnitude)| magnitude * magnitude).sum();
+}
+
+/// Fits uniform and normal distributions to `values` using maximum likelihood estimators
+/// and rates both fits with the Kolmogorov-Smirnov statistic. Every field is NaN without values
+pub fn fit_distribution(values: &[f64]) -> DistributionFit {
+	if values.is_empty() {
+		return DistributionFit {
+			uniform_low: f64::NAN,
+			uniform_high: f64::NAN,
+			uniform_ks: f64::NAN,
+			normal_mean: f64::NAN,
+			normal_std_dev: f64::NAN,
+			normal_ks: f64::NAN,
+		};
+	}
+	let mut sorted = values.to_vec();
+	sorted.sort_by(f64::total_cmp);
+
+	let uniform_low = sorted[0];
+	let uniform_high = sorted[sorted.len() - 1];
+	let uniform_width = uniform_high - uniform_low;
+	let uniform_ks = kolmogorov_smirnov(&sorted, |value| {
+		if uniform_width == 0.0 {
+			return if value < uniform_low { 0.0 } else { 1.0 };
+		}
+		return ((value - uniform_low) / uniform_width).clamp(0.0, 1.0);
+	});
+
+	let normal_mean = mean(&sorted);
+	let normal_std_dev = (sorted.iter().map(|value| (value - normal_mean).powi(2)).sum::<f64>() / sorted.len() as f64).sqrt();
+	let normal_ks = kolmogorov_smirnov(&sorted, |value| {
+		if normal_std_dev == 0.0 {
+			return if value < normal_mean { 0.0 } else { 1.0 };
+		}
+		return 0.5 * (1.0 + erf((value - normal_mean) / (normal_std_dev * std::f64::consts::SQRT_2)));
+	});
+
+	return DistributionFit {
+		uniform_low,
+		uniform_high,
+		uniform_ks,
+		normal_mean,
+		normal_std_dev,
+		normal_ks,
+	};
+}
+
+pub fn mean(values: &[f64]) -> f64 {
+	return values.iter().sum::<f64>() / values.len() as f64;
+}
+
+/// Frequency in Hz from rising crossings of the mean of `values` sampled at `times`
+/// Crossing instants are linearly interpolated between samples. NaN when there are fewer than two crossings
+pub fn zero_crossing_frequency(times: &[f64], values: &[f64]) -> f64 {
+	let average = mean(values);
+	let crossings: Vec<f64> = (1..values.len()).filter_map(|index| {
+		let before = values[index - 1] - average;
+		let after = values[index] - average;
+		if before >= 0.0 || after < 0.0 {
+			return None;
+		}
+		return Some(times[index - 1] + (times[index] - times[index - 1]) * -before / (after - before));
+	}).collect();
+	if crossings.len() < 2 {
+		return f64::NAN;
+	}
+	return (crossings.len() - 1) as f64 / (crossings[crossings.len() - 1] - crossings[0]);
+}
+
+/// Intervals where the short-time level of `values` sampled at `times` is at least `threshold_db` dBFS
+/// Level is measured in 10 ms frames, active parts shorter than `min_duration` s are dropped
+pub fn segment_by_energy(times: &[f64], values: &[f64], sampling_frequency: f64, threshold_db: f64, min_duration: f64) -> Vec<TimeInterval> {
+	let frame_length = ((0.01 * sampling_frequency).round() as usize).max(1);
+	let sample_period = sampling_frequency.recip();
+	let mut segments: Vec<TimeInterval> = Vec::new();
+	let mut is_active = false;
+	for (frame_times, frame) in times.chunks(frame_length).zip(values.chunks(frame_length)) {
+		let mean_square = frame.iter().map(|value| value * value).sum::<f64>() / frame.len() as f64;
+		let frame_active = 10.0 * mean_square.log10() >= threshold_db;
+		let frame_end = frame_times[frame_times.len() - 1] + sample_period;
+		match (frame_active, is_active) {
+			(true, true) => segments.last_mut().unwrap().end = frame_end,
+			(true, false) => segments.push(TimeInterval {
+				start: frame_times[0],
+				end: frame_end,
+			}),
+			_ => {}
+		}
+		is_active = frame_active;
+	}
+	segments.retain(|segment| segment.end - segment.start >= min_duration);
+	return segments;
+}
+
+/// Fundamental frequency in Hz between `min_freq` and `max_freq` found from the autocorrelation peak of `values`
+/// Peak position is refined by parabolic interpolation. NaN when the signal is not periodic enough
+pub fn autocorrelation_pitch(values: &[f64], sampling_frequency: f64, min_freq: f64, max_freq: f64) -> f64 {
+	let min_lag = ((sampling_frequency / max_freq).floor() as usize).max(1);
+	let max_lag = ((sampling_frequency / min_freq).ceil() as usize).min(values.len().saturating_sub(2));
+	if min_lag + 1 >= max_lag {
+		return f64::NAN;
+	}
+	let average = mean(values);
+	let centered: Vec<f64> = values.iter().map(|value| value - average).collect();
+	// normalized by the number of overlapping samples, so longer lags aren't penalized
+	let correlation = |lag: usize| centered.iter().zip(&centered[lag..]).map(|(x, y)| x * y).sum::<f64>() / (centered.len() - lag) as f64;
+	let energy = correlation(0);
+	if energy == 0.0 {
+		return f64::NAN;
+	}
+	let correlations: Vec<f64> = ((min_lag - 1)..=(max_lag + 1)).map(correlation).collect();
+	// first lag close to the global maximum wins, which avoids picking multiples of the period
+	let highest = correlations[1..correlations.len() - 1].iter().fold(f64::MIN, |peak, value| peak.max(*value));
+	if highest < 0.5 * energy {
+		return f64::NAN;
+	}
+	// the first and the last lag are skipped, a maximum there is just a slope towards a period outside of the range
+	let best = (2..correlations.len() - 2).find(|index| {
+		return correlations[*index] >= 0.9 * highest && correlations[*index] >= correlations[index - 1] && correlations[*index] >= correlations[index + 1];
+	});
+	let Some(best) = best else {
+		return f64::NAN;
+	};
+	// a real period is preceded by a dip, without it the peak is a ripple on the slope of a slower oscillation
+	let lowest = (1..min_lag - 1).map(correlation).chain(correlations[..best].iter().copied()).fold(f64::MAX, f64::min);
+	if lowest > 0.5 * correlations[best] {
+		return f64::NAN;
+	}
+	let (left, center, right) = (correlations[best - 1], correlations[best], correlations[best + 1]);
+	let curvature = left - 2.0 * center + right;
+	let offset = if curvature == 0.0 { 0.0 } else { 0.5 * (left - right) / curvature };
+	let lag = (best + min_lag - 1) as f64 + offset;
+	return sampling_frequency / lag;
+}
+
+/// Phase in radians by which `values_a` leads `values_b` at `frequency` Hz, between -π and π
+/// Both signals sampled at `times` are Hann windowed and correlated with a complex exponential, the phase is the angle of their cross-spectrum
+pub fn phase_difference(times: &[f64], values_a: &[f64], values_b: &[f64], frequency: f64) -> f64 {
+	let window = windows::hann(times.len());
+	let probe = |values: &[f64]| times.iter().zip(values).zip(&window).fold(Complex::ZERO, |sum, ((time, value), weight)| {
+		return sum + Complex::from_polar(value * weight, -TAU * frequency * time);
+	});
+	return (probe(values_a) * probe(values_b).conj()).arg();
+}
+
+/// One period of a periodic signal
+#[cfg_attr(feature = "wasm", wasm_bindgen)]
+pub struct PeriodExtraction {
+	/// Length of the period in s
+	pub period: f64,
+	cycle: Vec<f64>,
+	average_cycle: Vec<f64>,
+}
+
+#[cfg_attr(feature = "wasm", wasm_bindgen)]
+impl PeriodExtraction {
+	/// First full cycle, starting at a rising crossing of the mean
+	pub fn cycle(&self) -> Vec<f64> {
+		return self.cycle.clone();
+	}
+
+	/// Average of all full cycles aligned the same way as `cycle`, which suppresses noise uncorrelated with the period
+	pub fn average_cycle(&self) -> Vec<f64> {
+		return self.average_cycle.clone();
+	}
+}
+
+/// Frequency response of a system identified from its input and output
+#[cfg_attr(feature = "wasm", wasm_bindgen)]
+pub struct TransferFunctionEstimate {
+	frequencies: Vec<f64>,
+	response: Vec<Complex>,
+}
+
+#[cfg_attr(feature = "wasm", wasm_bindgen)]
+impl TransferFunctionEstimate {
+	/// Frequencies in Hz from DC to Nyquist frequency
+	pub fn frequencies(&self) -> Vec<f64> {
+		return self.frequencies.clone();
+	}
+
+	/// Linear gain at every frequency
+	pub fn magnitude(&self) -> Vec<f64> {
+		return self.response.iter().map(Complex::abs).collect();
+	}
+
+	/// Phase shift in radians at every frequency, between -π and π
+	pub fn phase(&self) -> Vec<f64> {
+		return self.response.iter().map(Complex::arg).collect();
+	}
+}
+
+/// Spectra of two signals averaged with Welch's method, one value per bin from DC to Nyquist frequency
+pub struct CrossSpectra {
+	/// Frequency of every bin in Hz
+	pub frequencies: Vec<f64>,
+	pub auto_a: Vec<f64>,
+	pub auto_b: Vec<f64>,
+	/// Cross-spectrum of conjugated `a` times `b`
+	pub cross: Vec<Complex>,
+}
+
+/// Averages spectra over Hann windowed segments of `values_a` and `values_b` overlapping by half
+/// `segment_length` is rounded up to a power of two and limited to the length of the signals, which need at least 2 samples
+pub fn welch_spectra(values_a: &[f64], values_b: &[f64], sampling_frequency: f64, segment_length: usize) -> Result<CrossSpectra, String> {
+	let length = values_a.len().min(values_b.len());
+	if length < 2 {
+		return Err(format!("Spectra need at least 2 samples, got {}", length));
+	}
+	let segment_length = segment_length.max(2).next_power_of_two().min(length.next_power_of_two());
+	let window = windows::hann(segment_length);
+	let bin_count = segment_length / 2 + 1;
+	let mut spectra = CrossSpectra {
+		frequencies: transforms::fft_frequencies(segment_length, sampling_frequency, true),
+		auto_a: vec![0.0; bin_count],
+		auto_b: vec![0.0; bin_count],
+		cross: vec![Complex::ZERO; bin_count],
+	};
+	let mut segment_start = 0;
+	loop {
+		// the last segment of a short signal is zero padded
+		let windowed = |values: &[f64]| -> Vec<f64> {
+			return window.iter().enumerate().map(|(offset, weight)| weight * values.get(segment_start + offset).copied().unwrap_or(0.0)).collect();
+		};
+		let spectrum_a = transforms::fft_real(&windowed(&values_a[..length]));
+		let spectrum_b = transforms::fft_real(&windowed(&values_b[..length]));
+		for bin in 0..bin_count {
+			spectra.auto_a[bin] += spectrum_a[bin].norm_sqr();
+			spectra.auto_b[bin] += spectrum_b[bin].norm_sqr();
+			spectra.cross[bin] = spectra.cross[bin] + spectrum_a[bin].conj() * spectrum_b[bin];
+		}
+		segment_start += (segment_length / 2).max(1);
+		if segment_start + segment_length > length {
+			break;
+		}
+	}
+	return Ok(spectra);
+}
+
+/// Magnitude squared coherence between 0 and 1 of `values_a` and `values_b` at every frequency of `welch_spectra`
+/// Bins where either signal has no power are 0
+pub fn coherence(values_a: &[f64], values_b: &[f64], sampling_frequency: f64, segment_length: usize) -> Result<Vec<CoordPair>, String> {
+	let spectra = welch_spectra(values_a, values_b, sampling_frequency, segment_length)?;
+	return Ok((0..spectra.frequencies.len()).map(|bin| {
+		let power = spectra.auto_a[bin] * spectra.auto_b[bin];
+		return CoordPair {
+			x: spectra.frequencies[bin],
+			y: if power > 0.0 { spectra.cross[bin].norm_sqr() / power } else { 0.0 },
+		};
+	}).collect());
+}
+
+/// H1 estimate of the system turning `input` into `output`, cross-spectrum divided by the input auto-spectrum
+/// Uncorrelated noise at the output averages out. Bins where the input has no power are 0
+pub fn estimate_transfer_function(input: &[f64], output: &[f64], sampling_frequency: f64, segment_length: usize) -> Result<TransferFunctionEstimate, String> {
+	let spectra = welch_spectra(input, output, sampling_frequency, segment_length)?;
+	let response = spectra.cross.iter().zip(&spectra.auto_a).map(|(cross, power)| if *power > 0.0 { cross.scale(power.recip()) } else { Complex::ZERO }).collect();
+	return Ok(TransferFunctionEstimate {
+		frequencies: spectra.frequencies,
+		response,
+	});
+}
+
+/// Finds the fundamental period of `values` from their autocorrelation and cuts it out
+/// Cycles are interpolated at the exact period, so each one has the number of samples closest to the period length
+/// None when fewer than two periods fit or the signal is not periodic enough
+pub fn extract_period(values: &[f64], sampling_frequency: f64) -> Option<PeriodExtraction> {
+	let frequency = autocorrelation_pitch(values, sampling_frequency, 2.0 * sampling_frequency / values.len() as f64, sampling_frequency / 2.0);
+	if frequency.is_nan() {
+		return None;
+	}
+	let period_samples = sampling_frequency / frequency;
+	let cycle_length = (period_samples.round() as usize).max(1);
+	let average = mean(values);
+	let start = (1..values.len()).find(|index| values[index - 1] < average && values[*index] >= average).map(|index| {
+		let (before, after) = (values[index - 1] - average, values[index] - average);
+		return (index - 1) as f64 + -before / (after - before);
+	}).unwrap_or(0.0);
+	let cycle_count = ((values.len() - 1) as f64 - start).div_euclid(period_samples) as usize;
+	if cycle_count == 0 {
+		return None;
+	}
+	let interpolate = |position: f64| {
+		let index = (position.floor() as usize).min(values.len() - 2);
+		let fraction = position - index as f64;
+		return values[index] + (values[index + 1] - values[index]) * fraction;
+	};
+	let get_cycle = |number: usize| (0..cycle_length).map(|offset| interpolate(start + (number as f64 + offset as f64 / cycle_length as f64) * period_samples)).collect::<Vec<f64>>();
+	let mut average_cycle = vec![0.0; cycle_length];
+	for number in 0..cycle_count {
+		for (sum, value) in average_cycle.iter_mut().zip(get_cycle(number)) {
+			*sum += value / cycle_count as f64;
+		}
+	}
+	return Some(PeriodExtraction {
+		period: period_samples / sampling_frequency,
+		cycle: get_cycle(0),
+		average_cycle,
+	});
+}
+
+/// Removes least-squares polynomial trend of given `order` from `values` sampled at `times`
+pub fn detrend(times: &[f64], values: &[f64], order: usize) -> Vec<f64> {
+	if times.is_empty() {
+		return Vec::new();
+	}
+	// times are mapped onto -1..1, powers of large times would make the system badly conditioned
+	let first = times[0];
+	let last = times[times.len() - 1];
+	let half_span = if last > first { (last - first) / 2.0 } else { 1.0 };
+	let scaled_times: Vec<f64> = times.iter().map(|time| (time - first) / half_span - 1.0).collect();
+
+	// normal equations A^T A c = A^T y, A being the Vandermonde matrix
+	let size = order + 1;
+	let mut matrix = vec![vec![0.0; size]; size];
+	let mut right_side = vec![0.0; size];
+	for (time, value) in scaled_times.iter().zip(values) {
+		let powers: Vec<f64> = (0..size).map(|power| time.powi(power as i32)).collect();
+		for row in 0..size {
+			for column in 0..size {
+				matrix[row][column] += powers[row] * powers[column];
+			}
+			right_side[row] += powers[row] * value;
+		}
+	}
+	let coefficients = solve_linear_system(matrix, right_side);
+	return scaled_times.iter().zip(values).map(|(time, value)| {
+		let trend = coefficients.iter().rev().fold(0.0, |accumulator, coefficient| accumulator * time + coefficient);
+		return value - trend;
+	}).collect();
+}
+
+/// Solves `matrix` * x = `right_side` by Gaussian elimination with partial pivoting
+/// Singular systems get zeros for undetermined unknowns
+pub fn solve_linear_system(mut matrix: Vec<Vec<f64>>, mut right_side: Vec<f64>) -> Vec<f64> {
+	let size = right_side.len();
+	for column in 0..size {
+		let pivot = (column..size).max_by(|x, y| matrix[*x][column].abs().total_cmp(&matrix[*y][column].abs())).unwrap();
+		matrix.swap(column, pivot);
+		right_side.swap(column, pivot);
+		if matrix[column][column].abs() < 1e-12 {
+			continue;
+		}
+		let pivot_row = matrix[column].clone();
+		for row in (column + 1)..size {
+			let factor = matrix[row][column] / pivot_row[column];
+			for (element, pivot_element) in matrix[row].iter_mut().zip(&pivot_row).skip(column) {
+				*element -= factor * pivot_element;
+			}
+			right_side[row] -= factor * right_side[column];
+		}
+	}
+	let mut solution = vec![0.0; size];
+	for row in (0..size).rev() {
+		if matrix[row][row].abs() < 1e-12 {
+			continue;
+		}
+		let known: f64 = ((row + 1)..size).map(|column| matrix[row][column] * solution[column]).sum();
+		solution[row] = (right_side[row] - known) / matrix[row][row];
+	}
+	return solution;
+}
+
+/// Largest distance between the empirical distribution of `sorted_values` and `cdf`
+fn kolmogorov_smirnov(sorted_values: &[f64], cdf: impl Fn(f64) -> f64) -> f64 {
+	let count = sorted_values.len() as f64;
+	return sorted_values.iter().enumerate().map(|(index, value)| {
+		let expected = cdf(*value);
+		// empirical cdf jumps at every sample, so both sides of the step need to be checked
+		let below = (expected - index as f64 / count).abs();
+		let above = ((index + 1) as f64 / count - expected).abs();
+		return below.max(above);
+	}).fold(0.0, f64::max);
+}
+
+/// Abramowitz and Stegun approximation 7.1.26, absolute error below 1.5e-7
+pub fn erf(x: f64) -> f64 {
+	let t = 1.0 / (1.0 + 0.3275911 * x.abs());
+	let polynomial = t * (0.254829592 + t * (-0.284496736 + t * (1.421413741 + t * (-1.453152027 + t * 1.061405429))));
+	let result = 1.0 - polynomial * (-x * x).exp();
+	return if x < 0.0 { -result } else { result };
+}
+
+#[cfg(test)]
+mod tests {
+	use super::*;
+
+	fn sine(frequency: f64, sampling_frequency: f64, length: usize) -> Vec<f64> {
+		return (0..length).map(|index| (TAU * frequency * index as f64 / sampling_frequency).sin()).collect();
+	}
+
+	#[test]
+	fn pitch_of_a_tone() {
+		let pitch = autocorrelation_pitch(&sine(440.0, 8000.0, 2048), 8000.0, 50.0, 2000.0);
+		assert!((pitch - 440.0).abs() < 0.5, "got {}", pitch);
+	}
+
+	#[test]
+	fn no_pitch_below_the_range() {
+		assert!(autocorrelation_pitch(&sine(10.0, 8000.0, 2048), 8000.0, 50.0, 2000.0).is_nan());
+	}
+
+	#[test]
+	fn no_pitch_of_a_ramp() {
+		let ramp: Vec<f64> = (0..1000).map(|index| index as f64 / 1000.0).collect();
+		assert!(autocorrelation_pitch(&ramp, 1000.0, 2.0, 500.0).is_nan());
+		assert!(extract_period(&ramp, 1000.0).is_none());
+	}
+
+	#[test]
+	fn distribution_of_no_values() {
+		let fit = fit_distribution(&[]);
+		assert!(fit.uniform_low.is_nan() && fit.uniform_ks.is_nan() && fit.normal_mean.is_nan() && fit.normal_ks.is_nan());
+	}
+	#[test]
+	fn spectra_of_short_signals() {
+		assert!(coherence(&[1.0], &[1.0], 1000.0, 256).is_err());
+		assert!(estimate_transfer_function(&[], &[], 1000.0, 1024).is_err());
+		let coherence = coherence(&[1.0, -1.0], &[0.5, -0.5], 1000.0, 256).unwrap();
+		assert_eq!(coherence.len(), 2);
+	}
+}
\ No newline at end of file
@@ -0,0 +1,72 @@
+/// Bytes every file of the format starts with
+const MAGIC: &[u8; 4] = b"SGNL";
+/// Version of the layout written by `encode`
+const VERSION: u16 = 1;
+/// Magic, version, sampling frequency, start time and sample count
+const HEADER_SIZE: usize = 4 + 2 + 8 + 8 + 8;
+
+/// Discrete signal with the metadata needed to place it in time, as stored in the binary format
+pub struct BinarySignal {
+	/// Sampling frequency in Hz
+	pub sampling_frequency: f64,
+	/// Time of the first sample in s
+	pub start_time: f64,
+	pub samples: Vec<f64>,
+}
+
+/// Little endian layout: "SGNL", u16 version, f64 sampling frequency, f64 start time, u64 sample count, f64 samples
+pub fn encode(signal: &BinarySignal) -> Vec<u8> {
+	let mut bytes = Vec::with_capacity(HEADER_SIZE + 8 * signal.samples.len());
+	bytes.extend_from_slice(MAGIC);
+	bytes.extend_from_slice(&VERSION.to_le_bytes());
+	bytes.extend_from_slice(&signal.sampling_frequency.to_le_bytes());
+	bytes.extend_from_slice(&signal.start_time.to_le_bytes());
+	bytes.extend_from_slice(&(signal.samples.len() as u64).to_le_bytes());
+	for sample in &signal.samples {
+		bytes.extend_from_slice(&sample.to_le_bytes());
+	}
+	return bytes;
+}
+
+/// Reads a signal written by `encode`
+pub fn decode(bytes: &[u8]) -> Result<BinarySignal, String> {
+	if bytes.len() < HEADER_SIZE || &bytes[0..4] != MAGIC {
+		return Err("Not a binary signal file".to_string());
+	}
+	let version = u16::from_le_bytes([bytes[4], bytes[5]]);
+	if version != VERSION {
+		return Err(format!("Unsupported binary signal version {}", version));
+	}
+	let read_f64 = |offset: usize| f64::from_le_bytes(bytes[offset..offset + 8].try_into().unwrap());
+	let count = u64::from_le_bytes(bytes[22..30].try_into().unwrap()) as usize;
+	if bytes.len() - HEADER_SIZE != count.saturating_mul(8) {
+		return Err(format!("Binary signal should have {} samples, but has {} bytes of them", count, bytes.len() - HEADER_SIZE));
+	}
+	let sampling_frequency = read_f64(6);
+	if !(sampling_frequency > 0.0 && sampling_frequency.is_finite()) {
+		return Err(format!("Invalid sampling frequency {}", sampling_frequency));
+	}
+	return Ok(BinarySignal {
+		sampling_frequency,
+		start_time: read_f64(14),
+		samples: (0..count).map(|index| read_f64(HEADER_SIZE + 8 * index)).collect(),
+	});
+}
+
+#[cfg(test)]
+mod tests {
+	use super::*;
+
+	#[test]
+	fn round_trip() {
+		let signal = BinarySignal {
+			sampling_frequency: 48000.0,
+			start_time: -0.25,
+			samples: vec![0.0, 1.5, -2.25, f64::MIN_POSITIVE, 1e300],
+		};
+		let decoded = decode(&encode(&signal)).unwrap();
+		assert_eq!(decoded.sampling_frequency, signal.sampling_frequency);
+		assert_eq!(decoded.start_time, signal.start_time);
+		assert_eq!(decoded.samples, signal.samples);
+	}
+}
\ No newline at end of file
@@ -0,0 +1,127 @@
+use std::ops::{Add, Div, Mul, Sub};
+
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub struct Complex {
+	pub re: f64,
+	pub im: f64,
+}
+
+impl Complex {
+	pub const ZERO: Self = Self { re: 0.0, im: 0.0 };
+	pub const ONE: Self = Self { re: 1.0, im: 0.0 };
+
+	pub fn new(re: f64, im: f64) -> Self {
+		return Self {
+			re,
+			im,
+		};
+	}
+
+	/// Creates a complex number from magnitude and angle in radians
+	pub fn from_polar(magnitude: f64, angle: f64) -> Self {
+		return Self {
+			re: magnitude * angle.cos(),
+			im: magnitude * angle.sin(),
+		};
+	}
+
+	pub fn abs(&self) -> f64 {
+		return self.re.hypot(self.im);
+	}
+
+	/// Angle in radians between -π and π
+	pub fn arg(&self) -> f64 {
+		return self.im.atan2(self.re);
+	}
+
+	pub fn conj(&self) -> Self {
+		return Self::new(self.re, -self.im);
+	}
+
+	pub fn norm_sqr(&self) -> f64 {
+		return self.re * self.re + self.im * self.im;
+	}
+
+	pub fn scale(&self, factor: f64) -> Self {
+		return Self {
+			re: self.re * factor,
+			im: self.im * factor,
+		};
+	}
+
+	/// Principal square root, with non-negative real part
+	pub fn sqrt(&self) -> Self {
+		return Self::from_polar(self.abs().sqrt(), self.arg() / 2.0);
+	}
+
+	pub fn powi(&self, exponent: i32) -> Self {
+		let mut result = Self::ONE;
+		for _ in 0..exponent.unsigned_abs() {
+			result = result * *self;
+		}
+		return if exponent < 0 { Self::ONE / result } else { result };
+	}
+}
+
+impl Add for Complex {
+	type Output = Self;
+	fn add(self, other: Self) -> Self {
+		return Self::new(self.re + other.re, self.im + other.im);
+	}
+}
+
+impl Sub for Complex {
+	type Output = Self;
+	fn sub(self, other: Self) -> Self {
+		return Self::new(self.re - other.re, self.im - other.im);
+	}
+}
+
+impl Mul for Complex {
+	type Output = Self;
+	fn mul(self, other: Self) -> Self {
+		return Self::new(self.re * other.re - self.im * other.im, self.re * other.im + self.im * other.re);
+	}
+}
+
+impl Div for Complex {
+	type Output = Self;
+	fn div(self, other: Self) -> Self {
+		let denominator = other.norm_sqr();
+		return Self::new((self.re * other.re + self.im * other.im) / denominator, (self.im * other.re - self.re * other.im) / denominator);
+	}
+}
+
+/// Evaluates polynomial with `coefficients` ordered from the highest power at `x` using Horner's scheme
+pub fn evaluate_polynomial(coefficients: &[f64], x: Complex) -> Complex {
+	return coefficients.iter().fold(Complex::ZERO, |accumulator, coefficient| accumulator * x + Complex::new(*coefficient, 0.0));
+}
+
+/// Finds all complex roots of polynomial with `coefficients` ordered from the highest power
+/// Uses the Durand-Kerner method
+pub fn polynomial_roots(coefficients: &[f64]) -> Vec<Complex> {
+	let first_nonzero = coefficients.iter().position(|coefficient| *coefficient != 0.0).unwrap_or(coefficients.len());
+	let coefficients = &coefficients[first_nonzero..];
+	if coefficients.len() < 2 {
+		return Vec::new();
+	}
+	let monic: Vec<f64> = coefficients.iter().map(|coefficient| coefficient / coefficients[0]).collect();
+	let degree = monic.len() - 1;
+
+	// starting points can't be real or symmetric, otherwise iteration can get stuck on conjugate pairs
+	let seed = Complex::new(0.4, 0.9);
+	let mut roots: Vec<Complex> = (0..degree).map(|index| seed.powi(index as i32)).collect();
+	for _ in 0..1000 {
+		let mut largest_step: f64 = 0.0;
+		for index in 0..degree {
+			let denominator = roots.iter().enumerate().filter(|(other_index, _)| *other_index != index).fold(Complex::ONE, |accumulator, (_, other)| accumulator * (roots[index] - *other));
+			let step = evaluate_polynomial(&monic, roots[index]) / denominator;
+			roots[index] = roots[index] - step;
+			largest_step = largest_step.max(step.abs());
+		}
+		if largest_step < 1e-14 {
+			break;
+		}
+	}
+	return roots;
+}
\ No newline at end of file
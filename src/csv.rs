@@ -0,0 +1,28 @@
+/// "time,value" header followed by one row per sample, numbers written so they read back exactly
+pub fn format(times: &[f64], values: &[f64]) -> String {
+	let mut text = String::from("time,value\n");
+	for (time, value) in times.iter().zip(values) {
+		text.push_str(&format!("{},{}\n", time, value));
+	}
+	return text;
+}
+
+/// Times and values from rows of two comma separated numbers, as written by `format`
+/// A first row that isn't numeric is taken as a header, blank lines are skipped
+pub fn parse(text: &str) -> Result<(Vec<f64>, Vec<f64>), String> {
+	let mut times = Vec::new();
+	let mut values = Vec::new();
+	for (line_index, line) in text.lines().enumerate().filter(|(_, line)| !line.trim().is_empty()) {
+		let fields: Vec<&str> = line.split(',').map(str::trim).collect();
+		let parsed: Result<Vec<f64>, _> = fields.iter().map(|field| field.parse::<f64>()).collect();
+		match parsed {
+			Ok(numbers) if numbers.len() == 2 => {
+				times.push(numbers[0]);
+				values.push(numbers[1]);
+			}
+			Err(_) if times.is_empty() && line_index == 0 => {}
+			_ => return Err(format!("Line {} is not a time and a value", line_index + 1)),
+		}
+	}
+	return Ok((times, values));
+}
\ No newline at end of file
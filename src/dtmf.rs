@@ -0,0 +1,80 @@
+#[cfg(feature = "wasm")]
+use wasm_bindgen::prelude::*;
+
+use crate::transforms;
+
+/// Row frequencies in Hz, from top to bottom of the keypad
+pub const ROW_FREQUENCIES: [f64; 4] = [697.0, 770.0, 852.0, 941.0];
+/// Column frequencies in Hz, from left to right of the keypad
+pub const COLUMN_FREQUENCIES: [f64; 4] = [1209.0, 1336.0, 1477.0, 1633.0];
+pub const KEYPAD: [[char; 4]; 4] = [
+	['1', '2', '3', 'A'],
+	['4', '5', '6', 'B'],
+	['7', '8', '9', 'C'],
+	['*', '0', '#', 'D'],
+];
+
+/// Length of a single analysis frame in s, short enough for the 40 ms minimum tone length
+const FRAME_DURATION: f64 = 0.02;
+
+#[cfg_attr(feature = "wasm", wasm_bindgen)]
+pub struct DtmfDigit {
+	/// Key that was pressed
+	pub symbol: char,
+	/// Time in s where the tone was first detected
+	pub start: f64,
+	/// Time in s where the tone was last detected
+	pub end: f64,
+}
+
+/// Finds DTMF key presses in `samples` starting at `start_time` with Goertzel detectors for all eight tones
+/// Consecutive frames with the same key are merged into a single press
+pub fn decode(samples: &[f64], start_time: f64, sampling_frequency: f64) -> Vec<DtmfDigit> {
+	let frame_length = ((FRAME_DURATION * sampling_frequency).round() as usize).max(1);
+	let mut digits: Vec<DtmfDigit> = Vec::new();
+	let mut previous_symbol = None;
+	for (frame_index, frame) in samples.chunks(frame_length).enumerate() {
+		let frame_start = start_time + (frame_index * frame_length) as f64 / sampling_frequency;
+		let frame_end = frame_start + frame.len() as f64 / sampling_frequency;
+		let symbol = detect_symbol(frame, sampling_frequency);
+		match (symbol, previous_symbol) {
+			(Some(symbol), Some(previous)) if symbol == previous => digits.last_mut().unwrap().end = frame_end,
+			(Some(symbol), _) => digits.push(DtmfDigit {
+				symbol,
+				start: frame_start,
+				end: frame_end,
+			}),
+			(None, _) => {}
+		}
+		previous_symbol = symbol;
+	}
+	return digits;
+}
+
+/// Key present in `frame`, if both of its tones are clearly stronger than all the others
+fn detect_symbol(frame: &[f64], sampling_frequency: f64) -> Option<char> {
+	let energy: f64 = frame.iter().map(|sample| sample * sample).sum();
+	if energy == 0.0 {
+		return None;
+	}
+	// a lone sine holding all of the frame energy gives relative power of 1, each tone of a key gives about 0.5
+	let relative_power = |frequency: f64| transforms::goertzel_power(frame, frequency, sampling_frequency) / (frame.len() as f64 * energy / 2.0);
+	let row_powers: Vec<f64> = ROW_FREQUENCIES.iter().map(|frequency| relative_power(*frequency)).collect();
+	let column_powers: Vec<f64> = COLUMN_FREQUENCIES.iter().map(|frequency| relative_power(*frequency)).collect();
+	let (row, row_power) = strongest(&row_powers);
+	let (column, column_power) = strongest(&column_powers);
+	const MINIMUM_RELATIVE_POWER: f64 = 0.25;
+	if row_power < MINIMUM_RELATIVE_POWER || column_power < MINIMUM_RELATIVE_POWER {
+		return None;
+	}
+	// other tones of the same group need to be much weaker, otherwise it's speech or noise
+	let is_dominant = |powers: &[f64], strongest_index: usize, strongest_power: f64| powers.iter().enumerate().all(|(index, power)| index == strongest_index || *power * 4.0 < strongest_power);
+	if !is_dominant(&row_powers, row, row_power) || !is_dominant(&column_powers, column, column_power) {
+		return None;
+	}
+	return Some(KEYPAD[row][column]);
+}
+
+fn strongest(powers: &[f64]) -> (usize, f64) {
+	return powers.iter().copied().enumerate().max_by(|x, y| x.1.total_cmp(&y.1)).unwrap();
+}
\ No newline at end of file
@@ -0,0 +1,233 @@
+use std::f64::consts::TAU;
+
+use rand::{rngs::StdRng, Rng};
+
+/// Silences `samples` when their level falls below `threshold`
+/// Gain opens over `attack_ms` and closes over `release_ms` milliseconds to avoid clicks
+pub fn noise_gate(samples: &[f64], sampling_frequency: f64, threshold: f64, attack_ms: f64, release_ms: f64) -> Vec<f64> {
+	let attack = smoothing_coefficient(attack_ms, sampling_frequency);
+	let release = smoothing_coefficient(release_ms, sampling_frequency);
+	let levels = envelope_follower(samples, attack, release);
+	let mut gain = 0.0;
+	return samples.iter().zip(levels).map(|(sample, level)| {
+		let target_gain = if level >= threshold { 1.0 } else { 0.0 };
+		let coefficient = if target_gain > gain { attack } else { release };
+		gain = target_gain + coefficient * (gain - target_gain);
+		return sample * gain;
+	}).collect();
+}
+
+/// Reduces level above `threshold_db` (dBFS) by `ratio`, following the envelope with `attack_ms` and `release_ms` milliseconds
+/// Infinite `ratio` with zero attack works as a brick-wall limiter, as the envelope never falls behind a rising peak
+pub fn compressor(samples: &[f64], sampling_frequency: f64, threshold_db: f64, ratio: f64, attack_ms: f64, release_ms: f64) -> Vec<f64> {
+	let attack = smoothing_coefficient(attack_ms, sampling_frequency);
+	let release = smoothing_coefficient(release_ms, sampling_frequency);
+	let levels = envelope_follower(samples, attack, release);
+	return samples.iter().zip(levels).map(|(sample, level)| {
+		let overshoot_db = 20.0 * level.log10() - threshold_db;
+		if overshoot_db <= 0.0 {
+			return *sample;
+		}
+		let gain_db = -overshoot_db * (1.0 - ratio.recip());
+		return sample * 10.0_f64.powf(gain_db / 20.0);
+	}).collect();
+}
+
+/// Schroeder reverberator - four parallel feedback combs followed by two series allpass filters
+/// `room_size` scales comb delays (1 being a medium room), `decay` is the time in s for the reverb to fall by 60 dB
+/// `mix` between 0 (dry) and 1 (wet)
+pub fn reverb(samples: &[f64], sampling_frequency: f64, room_size: f64, decay: f64, mix: f64) -> Vec<f64> {
+	// mutually prime delays in ms, so comb resonances don't line up
+	const COMB_DELAYS_MS: [f64; 4] = [29.7, 37.1, 41.1, 43.7];
+	const ALLPASS_DELAYS_MS: [f64; 2] = [5.0, 1.7];
+	const ALLPASS_GAIN: f64 = 0.7;
+
+	let mut wet = vec![0.0; samples.len()];
+	for delay_ms in COMB_DELAYS_MS {
+		let delay_seconds = delay_ms * room_size / 1000.0;
+		let feedback = 10.0_f64.powf(-3.0 * delay_seconds / decay);
+		let delayed = feedback_comb(samples, delay_samples(delay_seconds, sampling_frequency), feedback);
+		for (wet_sample, comb_sample) in wet.iter_mut().zip(delayed) {
+			*wet_sample += comb_sample / COMB_DELAYS_MS.len() as f64;
+		}
+	}
+	for delay_ms in ALLPASS_DELAYS_MS {
+		wet = allpass(&wet, delay_samples(delay_ms / 1000.0, sampling_frequency), ALLPASS_GAIN);
+	}
+	return mix_wet_dry(samples, &wet, mix);
+}
+
+/// y[n] = x[n] + feedback * y[n - delay]
+pub fn feedback_comb(samples: &[f64], delay: usize, feedback: f64) -> Vec<f64> {
+	let mut output: Vec<f64> = Vec::with_capacity(samples.len());
+	for (index, sample) in samples.iter().enumerate() {
+		let delayed = if index >= delay { output[index - delay] } else { 0.0 };
+		output.push(sample + feedback * delayed);
+	}
+	return output;
+}
+
+/// Schroeder allpass, y[n] = -gain * x[n] + x[n - delay] + gain * y[n - delay]
+pub fn allpass(samples: &[f64], delay: usize, gain: f64) -> Vec<f64> {
+	let mut output: Vec<f64> = Vec::with_capacity(samples.len());
+	for (index, sample) in samples.iter().enumerate() {
+		let (delayed_input, delayed_output) = if index >= delay { (samples[index - delay], output[index - delay]) } else { (0.0, 0.0) };
+		output.push(-gain * sample + delayed_input + gain * delayed_output);
+	}
+	return output;
+}
+
+/// Repeats the signal every `delay_seconds`, each echo scaled by `feedback`
+/// `mix` between 0 (dry) and 1 (wet)
+pub fn echo(samples: &[f64], sampling_frequency: f64, delay_seconds: f64, feedback: f64, mix: f64) -> Vec<f64> {
+	let delay = delay_samples(delay_seconds, sampling_frequency);
+	let mut wet: Vec<f64> = Vec::with_capacity(samples.len());
+	for index in 0..samples.len() {
+		// wet path holds only the echoes, feedback goes through the delay line again
+		let echoed = if index >= delay { samples[index - delay] + feedback * wet[index - delay] } else { 0.0 };
+		wet.push(echoed);
+	}
+	return mix_wet_dry(samples, &wet, mix);
+}
+
+/// Delays the signal by a time oscillating between `base_delay_ms` and `base_delay_ms + depth_ms` with `rate` Hz
+/// Basis of flanger and chorus effects. `mix` between 0 (dry) and 1 (wet)
+pub fn modulated_delay(samples: &[f64], sampling_frequency: f64, base_delay_ms: f64, depth_ms: f64, rate: f64, feedback: f64, mix: f64) -> Vec<f64> {
+	let mut delay_line: Vec<f64> = Vec::with_capacity(samples.len());
+	let mut wet: Vec<f64> = Vec::with_capacity(samples.len());
+	for (index, sample) in samples.iter().enumerate() {
+		let time = index as f64 / sampling_frequency;
+		let delay_ms = base_delay_ms + depth_ms * 0.5 * (1.0 - (TAU * rate * time).cos());
+		// at least one sample, the current one is not in the delay line yet
+		let delay = (delay_ms / 1000.0 * sampling_frequency).max(1.0);
+		let position = index as f64 - delay;
+		let delayed = if position >= 0.0 {
+			// fractional delay by linear interpolation between neighbouring samples
+			let earlier = position.floor() as usize;
+			let fraction = position - earlier as f64;
+			delay_line[earlier] * (1.0 - fraction) + delay_line[(earlier + 1).min(index - 1)] * fraction
+		} else {
+			0.0
+		};
+		delay_line.push(sample + feedback * delayed);
+		wet.push(delayed);
+	}
+	return mix_wet_dry(samples, &wet, mix);
+}
+
+/// Rounds samples to `bits` bit resolution over -1..1 and holds every `downsample_factor`th sample
+pub fn bitcrush(samples: &[f64], bits: u32, downsample_factor: usize) -> Vec<f64> {
+	let step = 2.0 / 2.0_f64.powi(bits as i32);
+	let downsample_factor = downsample_factor.max(1);
+	return (0..samples.len()).map(|index| {
+		let held = samples[index - index % downsample_factor];
+		return (held / step).round() * step;
+	}).collect();
+}
+
+/// Multiplies `signal` by a unit sine carrier of `carrier_freq` Hz, producing sum and difference frequencies
+pub fn ring_modulation(signal: Vec<crate::CoordPair>, carrier_freq: f64) -> Vec<crate::CoordPair> {
+	return signal.into_iter().map(|mut point| {
+		point.y *= (TAU * carrier_freq * point.x).sin();
+		return point;
+	}).collect();
+}
+
+/// Marks lost samples. Every received sample starts a run of `burst_length` lost samples with `loss_probability`
+pub fn dropout_mask(length: usize, loss_probability: f64, burst_length: usize, rng: &mut StdRng) -> Vec<bool> {
+	let mut lost = vec![false; length];
+	let mut index = 0;
+	while index < length {
+		if rng.gen_bool(loss_probability) {
+			let burst_end = (index + burst_length.max(1)).min(length);
+			lost[index..burst_end].fill(true);
+			index = burst_end;
+		} else {
+			index += 1;
+		}
+	}
+	return lost;
+}
+
+/// Whether `conceal_dropouts` accepts `concealment`
+pub fn is_concealment(concealment: &str) -> bool {
+	return matches!(concealment, "zero" | "remove" | "hold" | "linear");
+}
+
+/// Handles samples of `signal` marked in `lost` with `concealment`
+/// "zero" silences them, "remove" drops them, "hold" repeats the last received sample
+/// and "linear" interpolates between received neighbours
+pub fn conceal_dropouts(signal: Vec<crate::CoordPair>, lost: &[bool], concealment: &str) -> Vec<crate::CoordPair> {
+	if !is_concealment(concealment) {
+		panic!("Unknown concealment {}", concealment);
+	}
+	if concealment == "remove" {
+		return signal.into_iter().zip(lost).filter(|(_, lost)| !**lost).map(|(point, _)| point).collect();
+	}
+	let received: Vec<usize> = (0..signal.len()).filter(|index| !lost[*index]).collect();
+	let mut concealed: Vec<crate::CoordPair> = Vec::with_capacity(signal.len());
+	for (index, point) in signal.iter().enumerate() {
+		let y = if !lost[index] {
+			point.y
+		} else {
+			let next_position = received.partition_point(|received_index| *received_index < index);
+			let previous = next_position.checked_sub(1).map(|position| &signal[received[position]]);
+			let next = received.get(next_position).map(|next_index| &signal[*next_index]);
+			match (concealment, previous, next) {
+				("hold", Some(previous), _) => previous.y,
+				("linear", Some(previous), Some(next)) => previous.y + (next.y - previous.y) * (point.x - previous.x) / (next.x - previous.x),
+				("linear", Some(neighbour), None) | ("linear", None, Some(neighbour)) => neighbour.y,
+				// zeroing, or nothing received to conceal with
+				_ => 0.0,
+			}
+		};
+		concealed.push(crate::CoordPair {
+			x: point.x,
+			y,
+		});
+	}
+	return concealed;
+}
+
+/// Schmitt trigger - output switches to `amplitude` when input rises above `threshold_high`
+/// and to `-amplitude` when it falls below `threshold_low`, holding its state in between
+pub fn comparator(samples: &[f64], threshold_high: f64, threshold_low: f64, amplitude: f64) -> Vec<f64> {
+	let mut is_high = false;
+	return samples.iter().map(|sample| {
+		if *sample > threshold_high {
+			is_high = true;
+		} else if *sample < threshold_low {
+			is_high = false;
+		}
+		return if is_high { amplitude } else { -amplitude };
+	}).collect();
+}
+
+/// Blends processed `wet` samples with original `dry` ones, `mix` between 0 (dry) and 1 (wet)
+pub fn mix_wet_dry(dry: &[f64], wet: &[f64], mix: f64) -> Vec<f64> {
+	return dry.iter().zip(wet).map(|(dry_sample, wet_sample)| (1.0 - mix) * dry_sample + mix * wet_sample).collect();
+}
+
+/// Delay in whole samples, at least one so feedback loops stay causal
+fn delay_samples(delay_seconds: f64, sampling_frequency: f64) -> usize {
+	return ((delay_seconds * sampling_frequency).round() as usize).max(1);
+}
+
+/// Tracks the absolute value of `samples`, rising with `attack` and falling with `release` smoothing coefficients
+pub fn envelope_follower(samples: &[f64], attack: f64, release: f64) -> Vec<f64> {
+	let mut level = 0.0;
+	return samples.iter().map(|sample| {
+		let rectified = sample.abs();
+		let coefficient = if rectified > level { attack } else { release };
+		level = rectified + coefficient * (level - rectified);
+		return level;
+	}).collect();
+}
+
+/// One pole smoothing coefficient reaching about 63% of a step after `time_ms` milliseconds
+pub fn smoothing_coefficient(time_ms: f64, sampling_frequency: f64) -> f64 {
+	if time_ms <= 0.0 {
+		return 0.0;
+	}
+	return (-1.0 / (time_ms / 1000.0 * sampling_frequency)).exp();
+}
\ No newline at end of file
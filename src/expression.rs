@@ -0,0 +1,183 @@
+/// Parsed mathematical expression of time `t`
+#[derive(Clone, Debug)]
+pub enum Expression {
+	Number(f64),
+	Time,
+	Negate(Box<Expression>),
+	/// Operator is one of '+', '-', '*', '/' and '^'
+	Binary(char, Box<Expression>, Box<Expression>),
+	/// Function name and its argument
+	Call(String, Box<Expression>),
+}
+
+/// Functions of one argument which can be called in expressions
+const FUNCTIONS: [&str; 11] = ["sin", "cos", "tan", "exp", "ln", "log10", "sqrt", "abs", "floor", "ceil", "sign"];
+
+impl Expression {
+	/// Value of the expression at time `t` in s
+	pub fn evaluate(&self, t: f64) -> f64 {
+		return match self {
+			Expression::Number(value) => *value,
+			Expression::Time => t,
+			Expression::Negate(operand) => -operand.evaluate(t),
+			Expression::Binary(operator, left, right) => {
+				let (left, right) = (left.evaluate(t), right.evaluate(t));
+				match operator {
+					'+' => left + right,
+					'-' => left - right,
+					'*' => left * right,
+					'/' => left / right,
+					_ => left.powf(right),
+				}
+			}
+			Expression::Call(function, argument) => {
+				let argument = argument.evaluate(t);
+				match function.as_str() {
+					"sin" => argument.sin(),
+					"cos" => argument.cos(),
+					"tan" => argument.tan(),
+					"exp" => argument.exp(),
+					"ln" => argument.ln(),
+					"log10" => argument.log10(),
+					"sqrt" => argument.sqrt(),
+					"abs" => argument.abs(),
+					"floor" => argument.floor(),
+					"ceil" => argument.ceil(),
+					_ => if argument == 0.0 { 0.0 } else { argument.signum() },
+				}
+			}
+		};
+	}
+}
+
+/// Parses `text` like "2*sin(2*pi*5*t) + 0.3*t" with numbers, `t`, constants `pi` and `e`,
+/// operators + - * / ^ (right associative, binding tighter than unary minus), parentheses and functions from `FUNCTIONS`
+pub fn parse(text: &str) -> Result<Expression, String> {
+	let mut parser = Parser {
+		characters: text.chars().collect(),
+		position: 0,
+	};
+	let expression = parser.parse_sum()?;
+	parser.skip_whitespace();
+	if parser.position != parser.characters.len() {
+		return Err(format!("Unexpected '{}' at position {}", parser.characters[parser.position], parser.position));
+	}
+	return Ok(expression);
+}
+
+struct Parser {
+	characters: Vec<char>,
+	position: usize,
+}
+
+impl Parser {
+	fn skip_whitespace(&mut self) {
+		while self.position < self.characters.len() && self.characters[self.position].is_whitespace() {
+			self.position += 1;
+		}
+	}
+
+	/// Next non-whitespace character without consuming it
+	fn peek(&mut self) -> Option<char> {
+		self.skip_whitespace();
+		return self.characters.get(self.position).copied();
+	}
+
+	fn parse_sum(&mut self) -> Result<Expression, String> {
+		let mut expression = self.parse_product()?;
+		while let Some(operator) = self.peek().filter(|character| matches!(character, '+' | '-')) {
+			self.position += 1;
+			expression = Expression::Binary(operator, Box::new(expression), Box::new(self.parse_product()?));
+		}
+		return Ok(expression);
+	}
+
+	fn parse_product(&mut self) -> Result<Expression, String> {
+		let mut expression = self.parse_unary()?;
+		while let Some(operator) = self.peek().filter(|character| matches!(character, '*' | '/')) {
+			self.position += 1;
+			expression = Expression::Binary(operator, Box::new(expression), Box::new(self.parse_unary()?));
+		}
+		return Ok(expression);
+	}
+
+	fn parse_unary(&mut self) -> Result<Expression, String> {
+		return match self.peek() {
+			Some('-') => {
+				self.position += 1;
+				Ok(Expression::Negate(Box::new(self.parse_unary()?)))
+			}
+			Some('+') => {
+				self.position += 1;
+				self.parse_unary()
+			}
+			_ => self.parse_power(),
+		};
+	}
+
+	fn parse_power(&mut self) -> Result<Expression, String> {
+		let base = self.parse_atom()?;
+		if self.peek() == Some('^') {
+			self.position += 1;
+			// exponent may have its own sign, as in 2^-t
+			return Ok(Expression::Binary('^', Box::new(base), Box::new(self.parse_unary()?)));
+		}
+		return Ok(base);
+	}
+
+	fn parse_atom(&mut self) -> Result<Expression, String> {
+		let next = self.peek();
+		let start = self.position;
+		let is_digit_at = |position: usize| self.characters.get(position).is_some_and(char::is_ascii_digit);
+		return match next {
+			Some('(') => {
+				self.position += 1;
+				let expression = self.parse_sum()?;
+				if self.peek() != Some(')') {
+					return Err(format!("Expected ')' at position {}", self.position));
+				}
+				self.position += 1;
+				Ok(expression)
+			}
+			Some(character) if character.is_ascii_digit() || character == '.' => {
+				let mut end = start;
+				while is_digit_at(end) || self.characters.get(end) == Some(&'.') {
+					end += 1;
+				}
+				// exponent of scientific notation, as in 1.5e-3
+				if matches!(self.characters.get(end), Some('e' | 'E')) {
+					let sign_length = if matches!(self.characters.get(end + 1), Some('+' | '-')) { 1 } else { 0 };
+					if is_digit_at(end + 1 + sign_length) {
+						end += 1 + sign_length;
+						while is_digit_at(end) {
+							end += 1;
+						}
+					}
+				}
+				self.position = end;
+				let text: String = self.characters[start..end].iter().collect();
+				text.parse::<f64>().map(Expression::Number).map_err(|_| format!("Invalid number {} at position {}", text, start))
+			}
+			Some(character) if character.is_ascii_alphabetic() => {
+				while self.position < self.characters.len() && (self.characters[self.position].is_ascii_alphanumeric() || self.characters[self.position] == '_') {
+					self.position += 1;
+				}
+				let name: String = self.characters[start..self.position].iter().collect();
+				match name.as_str() {
+					"t" => Ok(Expression::Time),
+					"pi" => Ok(Expression::Number(std::f64::consts::PI)),
+					"e" => Ok(Expression::Number(std::f64::consts::E)),
+					_ if FUNCTIONS.contains(&name.as_str()) => {
+						if self.peek() != Some('(') {
+							return Err(format!("Expected '(' after {} at position {}", name, self.position));
+						}
+						Ok(Expression::Call(name, Box::new(self.parse_atom()?)))
+					}
+					_ => Err(format!("Unknown name {} at position {}", name, start)),
+				}
+			}
+			Some(character) => Err(format!("Unexpected '{}' at position {}", character, self.position)),
+			None => Err("Unexpected end of expression".to_string()),
+		};
+	}
+}
\ No newline at end of file
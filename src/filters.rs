@@ -0,0 +1,480 @@
+use std::f64::consts::{PI, TAU};
+
+#[cfg(feature = "wasm")]
+use wasm_bindgen::prelude::*;
+
+use crate::complex::{self, Complex};
+use crate::precision::Sample;
+use crate::progress::CANCELLED;
+
+/// Linear time-invariant filter described by its transfer function
+/// H(z) = (b0 + b1 * z^-1 + ... + bM * z^-M) / (a0 + a1 * z^-1 + ... + aN * z^-N)
+#[cfg_attr(feature = "wasm", wasm_bindgen)]
+#[derive(Clone)]
+pub struct Filter {
+	/// Feedforward coefficients b0..bM
+	numerator: Vec<f64>,
+	/// Feedback coefficients a0..aN
+	denominator: Vec<f64>,
+}
+
+#[cfg_attr(feature = "wasm", wasm_bindgen)]
+pub struct PoleZeroPlot {
+	poles: Vec<Complex>,
+	zeros: Vec<Complex>,
+}
+
+#[cfg_attr(feature = "wasm", wasm_bindgen)]
+pub struct ComplexValues {
+	values: Vec<Complex>,
+}
+
+#[cfg_attr(feature = "wasm", wasm_bindgen)]
+impl Filter {
+	pub fn new(numerator: Vec<f64>, denominator: Vec<f64>) -> Self {
+		return Self {
+			numerator,
+			denominator,
+		};
+	}
+
+	pub fn numerator(&self) -> Vec<f64> {
+		return self.numerator.clone();
+	}
+
+	pub fn denominator(&self) -> Vec<f64> {
+		return self.denominator.clone();
+	}
+
+	/// Filters `samples` with the difference equation a0 * y[n] = sum(bk * x[n - k]) - sum(ak * y[n - k]), starting from rest
+	pub fn apply(&self, samples: &[f64]) -> Vec<f64> {
+		let mut output: Vec<f64> = Vec::with_capacity(samples.len());
+		for index in 0..samples.len() {
+			let feedforward: f64 = self.numerator.iter().take(index + 1).enumerate().map(|(delay, coefficient)| coefficient * samples[index - delay]).sum();
+			let feedback: f64 = self.denominator.iter().take(index + 1).enumerate().skip(1).map(|(delay, coefficient)| coefficient * output[index - delay]).sum();
+			output.push((feedforward - feedback) / self.denominator[0]);
+		}
+		return output;
+	}
+
+	/// Poles and zeros of the transfer function in the z plane
+	pub fn poles_zeros(&self) -> PoleZeroPlot {
+		// both polynomials are brought to positive powers of z of the same degree,
+		// which keeps the poles or zeros at the origin caused by different lengths
+		let degree = self.numerator.len().max(self.denominator.len());
+		let mut numerator = self.numerator.clone();
+		numerator.resize(degree, 0.0);
+		let mut denominator = self.denominator.clone();
+		denominator.resize(degree, 0.0);
+		return PoleZeroPlot {
+			poles: complex::polynomial_roots(&denominator),
+			zeros: complex::polynomial_roots(&numerator),
+		};
+	}
+
+	/// Filter is stable when all poles lie strictly inside the unit circle
+	pub fn is_stable(&self) -> bool {
+		return self.poles_zeros().poles.iter().all(|pole| pole.abs() < 1.0);
+	}
+
+	/// Evaluates H(z) at points given by their real and imaginary parts
+	pub fn evaluate_transfer_function(&self, z_real: &[f64], z_imag: &[f64]) -> ComplexValues {
+		return ComplexValues {
+			values: z_real.iter().zip(z_imag).map(|(re, im)| self.transfer_function_at(Complex::new(*re, *im))).collect(),
+		};
+	}
+}
+
+impl Filter {
+	/// Group delay in samples at `frequency` in cycles per sample, the negative derivative of the phase response
+	/// Computed from the polynomials as Re(sum(k * bk * z^-k) / sum(bk * z^-k)) minus the same for the denominator, infinite at zeros on the unit circle
+	pub fn group_delay_at(&self, frequency: f64) -> f64 {
+		let polynomial_delay = |coefficients: &[f64]| -> f64 {
+			let (weighted, plain) = coefficients.iter().enumerate().fold((Complex::ZERO, Complex::ZERO), |(weighted, plain), (delay, coefficient)| {
+				let term = Complex::from_polar(*coefficient, -TAU * frequency * delay as f64);
+				return (weighted + term.scale(delay as f64), plain + term);
+			});
+			return (weighted / plain).re;
+		};
+		return polynomial_delay(&self.numerator) - polynomial_delay(&self.denominator);
+	}
+
+	pub fn transfer_function_at(&self, z: Complex) -> Complex {
+		let z_inverse = Complex::ONE / z;
+		// coefficients are ordered from the lowest power of z^-1, Horner's scheme needs the highest first
+		let numerator: Vec<f64> = self.numerator.iter().rev().copied().collect();
+		let denominator: Vec<f64> = self.denominator.iter().rev().copied().collect();
+		return complex::evaluate_polynomial(&numerator, z_inverse) / complex::evaluate_polynomial(&denominator, z_inverse);
+	}
+}
+
+/// Lowpass FIR with `cutoff` in cycles per sample (0 to 0.5), ideal sinc response truncated by `window`
+/// An odd window length gives a symmetric kernel with an integer delay, coefficients sum to 1
+pub fn fir_lowpass(cutoff: f64, window: &[f64]) -> Vec<f64> {
+	let center = (window.len() - 1) as f64 / 2.0;
+	let kernel: Vec<f64> = window.iter().enumerate().map(|(index, weight)| {
+		let offset = index as f64 - center;
+		let sinc = if offset == 0.0 { 2.0 * cutoff } else { (TAU * cutoff * offset).sin() / (PI * offset) };
+		return sinc * weight;
+	}).collect();
+	let sum: f64 = kernel.iter().sum();
+	return kernel.into_iter().map(|coefficient| coefficient / sum).collect();
+}
+
+/// Highpass FIR made by subtracting the lowpass of `fir_lowpass` from a unit impulse
+/// Needs an odd window length, even ones always have a zero at the Nyquist frequency
+pub fn fir_highpass(cutoff: f64, window: &[f64]) -> Result<Vec<f64>, String> {
+	return spectral_inversion(fir_lowpass(cutoff, window));
+}
+
+/// Bandpass FIR passing from `low_cutoff` to `high_cutoff` in cycles per sample, difference of two lowpasses
+pub fn fir_bandpass(low_cutoff: f64, high_cutoff: f64, window: &[f64]) -> Vec<f64> {
+	return fir_lowpass(high_cutoff, window).into_iter().zip(fir_lowpass(low_cutoff, window)).map(|(high, low)| high - low).collect();
+}
+
+/// Bandstop FIR rejecting from `low_cutoff` to `high_cutoff` in cycles per sample, needs an odd window length
+pub fn fir_bandstop(low_cutoff: f64, high_cutoff: f64, window: &[f64]) -> Result<Vec<f64>, String> {
+	return spectral_inversion(fir_bandpass(low_cutoff, high_cutoff, window));
+}
+
+/// Kernel with the complementary response of symmetric `kernel`, 1 - H
+fn spectral_inversion(mut kernel: Vec<f64>) -> Result<Vec<f64>, String> {
+	if kernel.len().is_multiple_of(2) {
+		return Err(format!("Kernel needs an odd number of taps, got {}", kernel.len()));
+	}
+	for coefficient in kernel.iter_mut() {
+		*coefficient = -*coefficient;
+	}
+	let center = kernel.len() / 2;
+	kernel[center] += 1.0;
+	return Ok(kernel);
+}
+
+/// Output of symmetric FIR `kernel` centered on `samples[index]`, so it doesn't delay the signal
+/// Samples outside of the signal are treated as zero
+pub fn centered_fir_at<T: Sample>(samples: &[T], kernel: &[T], index: usize) -> T {
+	let half_length = kernel.len() / 2;
+	return kernel.iter().enumerate().fold(T::default(), |sum, (kernel_index, coefficient)| {
+		return match (index + kernel_index).checked_sub(half_length) {
+			Some(sample_index) if sample_index < samples.len() => sum + *coefficient * samples[sample_index],
+			_ => sum,
+		};
+	});
+}
+
+#[cfg_attr(feature = "wasm", wasm_bindgen)]
+impl PoleZeroPlot {
+	pub fn poles_real(&self) -> Vec<f64> {
+		return self.poles.iter().map(|pole| pole.re).collect();
+	}
+
+	pub fn poles_imag(&self) -> Vec<f64> {
+		return self.poles.iter().map(|pole| pole.im).collect();
+	}
+
+	pub fn zeros_real(&self) -> Vec<f64> {
+		return self.zeros.iter().map(|zero| zero.re).collect();
+	}
+
+	pub fn zeros_imag(&self) -> Vec<f64> {
+		return self.zeros.iter().map(|zero| zero.im).collect();
+	}
+}
+
+#[cfg_attr(feature = "wasm", wasm_bindgen)]
+impl ComplexValues {
+	pub fn real(&self) -> Vec<f64> {
+		return self.values.iter().map(|value| value.re).collect();
+	}
+
+	pub fn imag(&self) -> Vec<f64> {
+		return self.values.iter().map(|value| value.im).collect();
+	}
+
+	pub fn magnitude(&self) -> Vec<f64> {
+		return self.values.iter().map(|value| value.abs()).collect();
+	}
+}
+
+/// Cascade of second-order sections, each one a `Filter` with three coefficients a side and a0 = 1
+#[cfg_attr(feature = "wasm", wasm_bindgen)]
+pub struct BiquadFilter {
+	sections: Vec<Filter>,
+}
+
+#[cfg_attr(feature = "wasm", wasm_bindgen)]
+impl BiquadFilter {
+	pub fn section_count(&self) -> usize {
+		return self.sections.len();
+	}
+
+	/// Coefficients of section at `index`
+	pub fn section(&self, index: usize) -> Filter {
+		return self.sections[index].clone();
+	}
+
+	/// Appends sections of `other` after the sections of this filter
+	pub fn cascade(&mut self, other: &BiquadFilter) {
+		self.sections.extend(other.sections.iter().cloned());
+	}
+
+	/// Filters `samples` through every section in turn, starting from rest
+	pub fn apply(&self, samples: &[f64]) -> Vec<f64> {
+		return self.sections.iter().fold(samples.to_vec(), |signal, section| section.apply(&signal));
+	}
+
+	/// Copy with every coefficient rounded to `fraction_bits` binary places, as in fixed-point hardware
+	/// Each section quantized on its own moves the poles much less than quantizing the single filter of `to_filter`
+	pub fn quantize_coefficients(&self, fraction_bits: u32) -> BiquadFilter {
+		let step = 0.5_f64.powi(fraction_bits as i32);
+		let quantize = |coefficients: &[f64]| coefficients.iter().map(|coefficient| (coefficient / step).round() * step).collect();
+		return BiquadFilter {
+			sections: self.sections.iter().map(|section| Filter::new(quantize(&section.numerator), quantize(&section.denominator))).collect(),
+		};
+	}
+
+	/// Single filter with the transfer function of the whole cascade, the product of transfer functions of the sections
+	pub fn to_filter(&self) -> Filter {
+		return Filter::new(
+			self.sections.iter().fold(vec![1.0], |product, section| convolve(&product, &section.numerator)),
+			self.sections.iter().fold(vec![1.0], |product, section| convolve(&product, &section.denominator)),
+		);
+	}
+}
+
+/// Second-order section of kind `kind` from the Audio EQ Cookbook by Robert Bristow-Johnson:
+/// "lowpass", "highpass", "bandpass" (0 dB peak), "notch", "peaking", "lowshelf" or "highshelf"
+/// `frequency` is the cutoff or center in cycles per sample, `gain_db` only affects peaking and shelving sections. None for unknown kinds
+pub fn biquad(kind: &str, frequency: f64, quality: f64, gain_db: f64) -> Option<BiquadFilter> {
+	let omega = TAU * frequency;
+	let (sin, cos) = omega.sin_cos();
+	let alpha = sin / (2.0 * quality);
+	let amplitude = 10.0_f64.powf(gain_db / 40.0);
+	let shelf = 2.0 * amplitude.sqrt() * alpha;
+	let (numerator, denominator) = match kind {
+		"lowpass" => ([(1.0 - cos) / 2.0, 1.0 - cos, (1.0 - cos) / 2.0], [1.0 + alpha, -2.0 * cos, 1.0 - alpha]),
+		"highpass" => ([(1.0 + cos) / 2.0, -(1.0 + cos), (1.0 + cos) / 2.0], [1.0 + alpha, -2.0 * cos, 1.0 - alpha]),
+		"bandpass" => ([alpha, 0.0, -alpha], [1.0 + alpha, -2.0 * cos, 1.0 - alpha]),
+		"notch" => ([1.0, -2.0 * cos, 1.0], [1.0 + alpha, -2.0 * cos, 1.0 - alpha]),
+		"peaking" => ([1.0 + alpha * amplitude, -2.0 * cos, 1.0 - alpha * amplitude], [1.0 + alpha / amplitude, -2.0 * cos, 1.0 - alpha / amplitude]),
+		"lowshelf" => (
+			[
+				amplitude * ((amplitude + 1.0) - (amplitude - 1.0) * cos + shelf),
+				2.0 * amplitude * ((amplitude - 1.0) - (amplitude + 1.0) * cos),
+				amplitude * ((amplitude + 1.0) - (amplitude - 1.0) * cos - shelf),
+			],
+			[
+				(amplitude + 1.0) + (amplitude - 1.0) * cos + shelf,
+				-2.0 * ((amplitude - 1.0) + (amplitude + 1.0) * cos),
+				(amplitude + 1.0) + (amplitude - 1.0) * cos - shelf,
+			],
+		),
+		"highshelf" => (
+			[
+				amplitude * ((amplitude + 1.0) + (amplitude - 1.0) * cos + shelf),
+				-2.0 * amplitude * ((amplitude - 1.0) + (amplitude + 1.0) * cos),
+				amplitude * ((amplitude + 1.0) + (amplitude - 1.0) * cos - shelf),
+			],
+			[
+				(amplitude + 1.0) - (amplitude - 1.0) * cos + shelf,
+				2.0 * ((amplitude - 1.0) - (amplitude + 1.0) * cos),
+				(amplitude + 1.0) - (amplitude - 1.0) * cos - shelf,
+			],
+		),
+		_ => return None,
+	};
+	let normalization = denominator[0];
+	return Some(BiquadFilter {
+		sections: vec![Filter::new(
+			numerator.iter().map(|coefficient| coefficient / normalization).collect(),
+			denominator.iter().map(|coefficient| coefficient / normalization).collect(),
+		)],
+	});
+}
+
+/// Digital IIR of `order` from an analog prototype `family`, "butterworth" or "chebyshev" (type I with `ripple_db` of passband ripple),
+/// mapped with the bilinear transform. `kind` is "lowpass" or "highpass" with one edge in `edges`, or "bandpass" or "bandstop" with two,
+/// all in cycles per sample. Realized as second-order sections, band kinds have twice as many poles as `order`
+pub fn analog_prototype_iir(family: &str, kind: &str, order: usize, edges: &[f64], ripple_db: f64) -> Result<BiquadFilter, String> {
+	if order == 0 {
+		return Err("Filter order must be at least 1".to_string());
+	}
+	let prototype: Vec<Complex> = match family {
+		"butterworth" => (0..order).map(|index| Complex::from_polar(1.0, PI * (2 * index + order + 1) as f64 / (2 * order) as f64)).collect(),
+		"chebyshev" => {
+			if ripple_db <= 0.0 {
+				return Err(format!("Passband ripple must be positive, got {} dB", ripple_db));
+			}
+			let epsilon = (10.0_f64.powf(ripple_db / 10.0) - 1.0).sqrt();
+			let spread = (1.0 / epsilon).asinh() / order as f64;
+			(0..order).map(|index| {
+				let angle = PI * (2 * index + 1) as f64 / (2 * order) as f64;
+				return Complex::new(-spread.sinh() * angle.sin(), spread.cosh() * angle.cos());
+			}).collect()
+		}
+		_ => return Err(format!("Unknown filter family {}", family)),
+	};
+	// the prototype passes 0 rad/s with gain 1, except even order Chebyshev filters sitting at the bottom of the ripple
+	let reference_gain = if family == "chebyshev" && order.is_multiple_of(2) { 10.0_f64.powf(-ripple_db / 20.0) } else { 1.0 };
+
+	// edges prewarped, so they land exactly where requested after the bilinear transform z = (1 + s) / (1 - s)
+	let warped: Vec<f64> = edges.iter().map(|edge| (PI * edge).tan()).collect();
+	let expected_edges = if matches!(kind, "bandpass" | "bandstop") { 2 } else { 1 };
+	if edges.len() != expected_edges || edges.iter().any(|edge| *edge <= 0.0 || *edge >= 0.5) || (expected_edges == 2 && edges[0] >= edges[1]) {
+		return Err(format!("{} needs {} increasing edges between 0 and the Nyquist frequency", kind, expected_edges));
+	}
+	let mut poles = Vec::new();
+	let mut zeros = Vec::new();
+	// point of the unit circle where the digital filter has the gain of the prototype at 0 rad/s
+	let reference = match kind {
+		"lowpass" => {
+			poles.extend(prototype.iter().map(|pole| pole.scale(warped[0])));
+			Complex::ONE
+		}
+		"highpass" => {
+			poles.extend(prototype.iter().map(|pole| Complex::new(warped[0], 0.0) / *pole));
+			zeros.extend(vec![Complex::ZERO; order]);
+			Complex::new(-1.0, 0.0)
+		}
+		"bandpass" | "bandstop" => {
+			let bandwidth = warped[1] - warped[0];
+			let center_squared = warped[0] * warped[1];
+			for pole in &prototype {
+				// every prototype pole p splits into the roots of s^2 - p * B * s + w0^2, or s^2 - B / p * s + w0^2 for bandstop
+				let half_sum = if kind == "bandpass" { pole.scale(bandwidth / 2.0) } else { Complex::new(bandwidth / 2.0, 0.0) / *pole };
+				let offset = (half_sum * half_sum - Complex::new(center_squared, 0.0)).sqrt();
+				poles.push(half_sum + offset);
+				poles.push(half_sum - offset);
+			}
+			if kind == "bandpass" {
+				zeros.extend(vec![Complex::ZERO; order]);
+			} else {
+				for _ in 0..order {
+					zeros.push(Complex::new(0.0, center_squared.sqrt()));
+					zeros.push(Complex::new(0.0, -center_squared.sqrt()));
+				}
+			}
+			let center = 2.0 * center_squared.sqrt().atan();
+			if kind == "bandpass" { Complex::from_polar(1.0, center) } else { Complex::ONE }
+		}
+		_ => return Err(format!("Unknown filter kind {}", kind)),
+	};
+
+	let bilinear = |s: &Complex| (Complex::ONE + *s) / (Complex::ONE - *s);
+	let digital_poles: Vec<Complex> = poles.iter().map(bilinear).collect();
+	let mut digital_zeros: Vec<Complex> = zeros.iter().map(bilinear).collect();
+	// zeros at infinity of the s plane end up at the Nyquist frequency
+	digital_zeros.resize(digital_poles.len(), Complex::new(-1.0, 0.0));
+
+	let mut sections: Vec<Filter> = group_conjugates(&digital_poles).into_iter().zip(group_conjugates(&digital_zeros)).map(|(pole_group, zero_group)| {
+		return Filter::new(real_polynomial(&zero_group), real_polynomial(&pole_group));
+	}).collect();
+	let gain = BiquadFilter { sections: sections.clone() }.to_filter().transfer_function_at(reference).abs();
+	let correction = (reference_gain / gain).powf(1.0 / sections.len() as f64);
+	for section in &mut sections {
+		section.numerator.iter_mut().for_each(|coefficient| *coefficient *= correction);
+	}
+	return Ok(BiquadFilter { sections });
+}
+
+/// Lowest order of `family` meeting `ripple_db` of loss at `passband_edge` and `attenuation_db` at `stopband_edge`,
+/// both in cycles per sample. Edges in that order describe a lowpass, swapped a highpass
+pub fn minimum_iir_order(family: &str, passband_edge: f64, stopband_edge: f64, ripple_db: f64, attenuation_db: f64) -> Result<usize, String> {
+	if ripple_db <= 0.0 || attenuation_db <= ripple_db {
+		return Err("Stopband attenuation must exceed a positive passband ripple".to_string());
+	}
+	let (passband, stopband) = ((PI * passband_edge).tan(), (PI * stopband_edge).tan());
+	let selectivity = if passband < stopband { stopband / passband } else { passband / stopband };
+	let discrimination = ((10.0_f64.powf(attenuation_db / 10.0) - 1.0) / (10.0_f64.powf(ripple_db / 10.0) - 1.0)).sqrt();
+	let order = match family {
+		"butterworth" => discrimination.ln() / selectivity.ln(),
+		"chebyshev" => discrimination.acosh() / selectivity.acosh(),
+		_ => return Err(format!("Unknown filter family {}", family)),
+	};
+	return Ok((order - 1e-9).ceil().max(1.0) as usize);
+}
+
+/// Splits `roots` into complex conjugate pairs and pairs of real roots, with a lone real root last
+fn group_conjugates(roots: &[Complex]) -> Vec<Vec<Complex>> {
+	let tolerance = 1e-10;
+	let mut groups: Vec<Vec<Complex>> = roots.iter().filter(|root| root.im > tolerance).map(|root| vec![*root, root.conj()]).collect();
+	let real: Vec<Complex> = roots.iter().filter(|root| root.im.abs() <= tolerance).map(|root| Complex::new(root.re, 0.0)).collect();
+	groups.extend(real.chunks(2).map(|chunk| chunk.to_vec()));
+	return groups;
+}
+
+/// Coefficients of the product of (1 - r * z^-1) over `roots`, which come in conjugate pairs, so they are real
+fn real_polynomial(roots: &[Complex]) -> Vec<f64> {
+	let mut coefficients = vec![Complex::ONE];
+	for root in roots {
+		let mut next = vec![Complex::ZERO; coefficients.len() + 1];
+		for (index, coefficient) in coefficients.iter().enumerate() {
+			next[index] = next[index] + *coefficient;
+			next[index + 1] = next[index + 1] - *coefficient * *root;
+		}
+		coefficients = next;
+	}
+	return coefficients.into_iter().map(|coefficient| coefficient.re).collect();
+}
+
+/// Sliding window smoothing of `samples` with windows of `length` samples centered on each one, so nothing is delayed
+/// `kind` is "moving_average", "weighted_moving_average" with triangular weights peaking at the center, or "median"
+/// Windows are cut short at the ends of the signal. None for unknown kinds
+pub fn smooth(samples: &[f64], kind: &str, length: usize) -> Option<Vec<f64>> {
+	let length = length.max(1);
+	let half = (length - 1) / 2;
+	let weights: Vec<f64> = match kind {
+		"moving_average" | "median" => vec![1.0; length],
+		"weighted_moving_average" => (0..length).map(|index| (index.min(length - 1 - index) + 1) as f64).collect(),
+		_ => return None,
+	};
+	return Some((0..samples.len()).map(|index| {
+		let first = index.saturating_sub(half);
+		let last = (index + length - 1 - half).min(samples.len() - 1);
+		let window = &samples[first..=last];
+		if kind == "median" {
+			let mut sorted = window.to_vec();
+			sorted.sort_by(f64::total_cmp);
+			let middle = sorted.len() / 2;
+			return if sorted.len() % 2 == 1 { sorted[middle] } else { (sorted[middle - 1] + sorted[middle]) / 2.0 };
+		}
+		let window_weights = &weights[first + half - index..=last + half - index];
+		return window.iter().zip(window_weights).map(|(value, weight)| value * weight).sum::<f64>() / window_weights.iter().sum::<f64>();
+	}).collect());
+}
+
+/// Full linear convolution of `a` and `b`, also the product of polynomials with these coefficients
+pub fn convolve(a: &[f64], b: &[f64]) -> Vec<f64> {
+	return convolve_reporting(a, b, &mut |_| true).unwrap_or_default();
+}
+
+/// `convolve` calling `progress` with the fraction of `a` processed, stopping with an error once it returns false
+pub fn convolve_reporting(a: &[f64], b: &[f64], progress: &mut dyn FnMut(f64) -> bool) -> Result<Vec<f64>, String> {
+	if a.is_empty() || b.is_empty() {
+		return Ok(Vec::new());
+	}
+	let mut result = vec![0.0; a.len() + b.len() - 1];
+	for (index_a, value_a) in a.iter().enumerate() {
+		for (index_b, value_b) in b.iter().enumerate() {
+			result[index_a + index_b] += value_a * value_b;
+		}
+		if !progress((index_a + 1) as f64 / a.len() as f64) {
+			return Err(CANCELLED.to_string());
+		}
+	}
+	return Ok(result);
+}
+
+#[cfg(test)]
+mod tests {
+	use super::*;
+
+	#[test]
+	fn butterworth_is_3_db_down_at_the_cutoff() {
+		for (kind, order) in [("lowpass", 2), ("lowpass", 5), ("highpass", 4)] {
+			let filter = analog_prototype_iir("butterworth", kind, order, &[0.1], 0.0).unwrap().to_filter();
+			let gain_db = 20.0 * filter.transfer_function_at(Complex::from_polar(1.0, TAU * 0.1)).abs().log10();
+			assert!((gain_db + 10.0 * 2.0_f64.log10()).abs() < 1e-9, "{} of order {}: {} dB", kind, order, gain_db);
+		}
+	}
+}
\ No newline at end of file
@@ -0,0 +1,76 @@
+#[cfg(feature = "wasm")]
+use wasm_bindgen::prelude::*;
+
+use crate::{analysis, transforms, windows};
+
+/// Analyzes live input pushed in chunks, for example microphone data captured with getUserMedia
+/// Keeps the latest samples in a ring buffer and computes estimates over it on demand
+#[cfg_attr(feature = "wasm", wasm_bindgen)]
+pub struct InputAnalyzer {
+	/// Sampling frequency of pushed samples in Hz
+	sampling_frequency: f64,
+	/// Latest samples, oldest one at `write_position` once the buffer is full
+	buffer: Vec<f64>,
+	/// Where the next pushed sample goes
+	write_position: usize,
+	/// Number of samples pushed so far, up to the buffer length
+	filled: usize,
+}
+
+#[cfg_attr(feature = "wasm", wasm_bindgen)]
+impl InputAnalyzer {
+	/// `buffer_size` is the number of latest samples used for analysis
+	pub fn new(sampling_frequency: f64, buffer_size: usize) -> Self {
+		return Self {
+			sampling_frequency,
+			buffer: vec![0.0; buffer_size.max(1)],
+			write_position: 0,
+			filled: 0,
+		};
+	}
+
+	pub fn push_samples(&mut self, chunk: &[f32]) {
+		for sample in chunk {
+			self.buffer[self.write_position] = *sample as f64;
+			self.write_position = (self.write_position + 1) % self.buffer.len();
+			self.filled = (self.filled + 1).min(self.buffer.len());
+		}
+	}
+
+	/// Hann windowed magnitude spectrum of the buffered samples, bins from DC to Nyquist frequency
+	/// Frequencies of bins come from `fft_frequencies` with the buffer size rounded up to a power of two
+	pub fn get_spectrum(&self) -> Vec<f64> {
+		let samples = self.get_samples();
+		let windowed: Vec<f64> = samples.iter().zip(windows::hann(samples.len())).map(|(sample, weight)| sample * weight).collect();
+		let spectrum = transforms::fft_real(&windowed);
+		return spectrum[..=spectrum.len() / 2].iter().map(|value| value.abs()).collect();
+	}
+
+	/// RMS level of the buffered samples in dBFS
+	pub fn get_level_db(&self) -> f64 {
+		let samples = self.get_samples();
+		let mean_square = samples.iter().map(|sample| sample * sample).sum::<f64>() / samples.len().max(1) as f64;
+		return 10.0 * mean_square.log10();
+	}
+
+	/// Peak level of the buffered samples in dBFS
+	pub fn get_peak_db(&self) -> f64 {
+		let peak = self.get_samples().iter().fold(0.0, |peak: f64, sample| peak.max(sample.abs()));
+		return 20.0 * peak.log10();
+	}
+
+	/// Fundamental frequency in Hz between 50 Hz and 2 kHz, NaN when the input is not periodic
+	pub fn get_pitch(&self) -> f64 {
+		return analysis::autocorrelation_pitch(&self.get_samples(), self.sampling_frequency, 50.0, 2000.0);
+	}
+}
+
+impl InputAnalyzer {
+	/// Buffered samples in the order they were pushed
+	fn get_samples(&self) -> Vec<f64> {
+		if self.filled < self.buffer.len() {
+			return self.buffer[..self.filled].to_vec();
+		}
+		return self.buffer[self.write_position..].iter().chain(&self.buffer[..self.write_position]).copied().collect();
+	}
+}
\ No newline at end of file
@@ -0,0 +1,357 @@
+/// Minimal JSON document model used for project files and signal descriptions
+#[derive(Clone, Debug, PartialEq)]
+pub enum JsonValue {
+	Null,
+	Bool(bool),
+	Number(f64),
+	String(String),
+	Array(Vec<JsonValue>),
+	/// Keys are kept in insertion order
+	Object(Vec<(String, JsonValue)>),
+}
+
+impl JsonValue {
+	/// Value under `key` if this is an object containing it
+	pub fn get(&self, key: &str) -> Option<&JsonValue> {
+		return match self {
+			JsonValue::Object(entries) => entries.iter().find(|(entry_key, _)| entry_key == key).map(|(_, value)| value),
+			_ => None,
+		};
+	}
+
+	pub fn as_f64(&self) -> Option<f64> {
+		return match self {
+			JsonValue::Number(number) => Some(*number),
+			_ => None,
+		};
+	}
+
+	pub fn as_bool(&self) -> Option<bool> {
+		return match self {
+			JsonValue::Bool(value) => Some(*value),
+			_ => None,
+		};
+	}
+
+	pub fn as_str(&self) -> Option<&str> {
+		return match self {
+			JsonValue::String(string) => Some(string),
+			_ => None,
+		};
+	}
+
+	pub fn as_array(&self) -> Option<&[JsonValue]> {
+		return match self {
+			JsonValue::Array(values) => Some(values),
+			_ => None,
+		};
+	}
+
+	pub fn as_object(&self) -> Option<&[(String, JsonValue)]> {
+		return match self {
+			JsonValue::Object(entries) => Some(entries),
+			_ => None,
+		};
+	}
+
+	/// Serializes the value without any whitespace
+	pub fn stringify(&self) -> String {
+		let mut output = String::new();
+		self.write_to(&mut output);
+		return output;
+	}
+
+	fn write_to(&self, output: &mut String) {
+		match self {
+			JsonValue::Null => output.push_str("null"),
+			JsonValue::Bool(value) => output.push_str(if *value { "true" } else { "false" }),
+			// JSON has no representation of infinities and NaN
+			JsonValue::Number(number) => output.push_str(&if number.is_finite() { number.to_string() } else { "null".to_string() }),
+			JsonValue::String(string) => write_string(string, output),
+			JsonValue::Array(values) => {
+				output.push('[');
+				for (index, value) in values.iter().enumerate() {
+					if index > 0 {
+						output.push(',');
+					}
+					value.write_to(output);
+				}
+				output.push(']');
+			}
+			JsonValue::Object(entries) => {
+				output.push('{');
+				for (index, (key, value)) in entries.iter().enumerate() {
+					if index > 0 {
+						output.push(',');
+					}
+					write_string(key, output);
+					output.push(':');
+					value.write_to(output);
+				}
+				output.push('}');
+			}
+		}
+	}
+}
+
+fn write_string(string: &str, output: &mut String) {
+	output.push('"');
+	for character in string.chars() {
+		match character {
+			'"' => output.push_str("\\\""),
+			'\\' => output.push_str("\\\\"),
+			'\n' => output.push_str("\\n"),
+			'\r' => output.push_str("\\r"),
+			'\t' => output.push_str("\\t"),
+			character if (character as u32) < 0x20 => output.push_str(&format!("\\u{:04x}", character as u32)),
+			character => output.push(character),
+		}
+	}
+	output.push('"');
+}
+
+/// Parses a complete JSON document
+pub fn parse(text: &str) -> Result<JsonValue, String> {
+	let mut parser = Parser {
+		characters: text.chars().collect(),
+		position: 0,
+	};
+	let value = parser.parse_value()?;
+	parser.skip_whitespace();
+	if parser.position != parser.characters.len() {
+		return Err(format!("Unexpected trailing characters at position {}", parser.position));
+	}
+	return Ok(value);
+}
+
+struct Parser {
+	characters: Vec<char>,
+	position: usize,
+}
+
+impl Parser {
+	fn skip_whitespace(&mut self) {
+		while matches!(self.peek(), Some(' ' | '\t' | '\n' | '\r')) {
+			self.position += 1;
+		}
+	}
+
+	fn peek(&self) -> Option<char> {
+		return self.characters.get(self.position).copied();
+	}
+
+	fn expect(&mut self, expected: char) -> Result<(), String> {
+		self.skip_whitespace();
+		if self.peek() != Some(expected) {
+			return Err(format!("Expected '{}' at position {}", expected, self.position));
+		}
+		self.position += 1;
+		return Ok(());
+	}
+
+	fn parse_value(&mut self) -> Result<JsonValue, String> {
+		self.skip_whitespace();
+		return match self.peek() {
+			Some('{') => self.parse_object(),
+			Some('[') => self.parse_array(),
+			Some('"') => Ok(JsonValue::String(self.parse_string()?)),
+			Some('t') => self.parse_literal("true", JsonValue::Bool(true)),
+			Some('f') => self.parse_literal("false", JsonValue::Bool(false)),
+			Some('n') => self.parse_literal("null", JsonValue::Null),
+			Some(character) if character == '-' || character.is_ascii_digit() => self.parse_number(),
+			Some(character) => Err(format!("Unexpected character '{}' at position {}", character, self.position)),
+			None => Err("Unexpected end of input".to_string()),
+		};
+	}
+
+	fn parse_literal(&mut self, literal: &str, value: JsonValue) -> Result<JsonValue, String> {
+		for expected in literal.chars() {
+			if self.peek() != Some(expected) {
+				return Err(format!("Invalid literal at position {}", self.position));
+			}
+			self.position += 1;
+		}
+		return Ok(value);
+	}
+
+	/// Number following the JSON grammar, which has no leading zeros, leading plus or dot without digits on both sides
+	fn parse_number(&mut self) -> Result<JsonValue, String> {
+		let start = self.position;
+		self.skip_if(|character| character == '-');
+		if self.peek() == Some('0') {
+			self.position += 1;
+		} else if self.skip_digits() == 0 {
+			return Err(format!("Invalid number at position {}", start));
+		}
+		if self.skip_if(|character| character == '.') && self.skip_digits() == 0 {
+			return Err(format!("Missing digits after the decimal point at position {}", self.position));
+		}
+		if self.skip_if(|character| matches!(character, 'e' | 'E')) {
+			self.skip_if(|character| matches!(character, '+' | '-'));
+			if self.skip_digits() == 0 {
+				return Err(format!("Missing exponent digits at position {}", self.position));
+			}
+		}
+		if self.peek().is_some_and(|character| character.is_ascii_digit() || character == '.') {
+			return Err(format!("Invalid number at position {}", start));
+		}
+		let text: String = self.characters[start..self.position].iter().collect();
+		return text.parse::<f64>().map(JsonValue::Number).map_err(|_| format!("Invalid number {} at position {}", text, start));
+	}
+
+	/// Moves past the next character if it satisfies `condition`, returns whether it did
+	fn skip_if(&mut self, condition: impl Fn(char) -> bool) -> bool {
+		if self.peek().is_some_and(condition) {
+			self.position += 1;
+			return true;
+		}
+		return false;
+	}
+
+	/// Moves past consecutive digits, returns how many there were
+	fn skip_digits(&mut self) -> usize {
+		let start = self.position;
+		while self.skip_if(|character| character.is_ascii_digit()) {}
+		return self.position - start;
+	}
+
+	/// Code unit of a \u escape, after the "\u"
+	fn parse_code_unit(&mut self) -> Result<u32, String> {
+		let digits: String = self.characters.get(self.position..self.position + 4).ok_or("Unterminated escape")?.iter().collect();
+		if !digits.chars().all(|digit| digit.is_ascii_hexdigit()) {
+			return Err(format!("Invalid escape \\u{}", digits));
+		}
+		self.position += 4;
+		return Ok(u32::from_str_radix(&digits, 16).unwrap());
+	}
+
+	fn parse_string(&mut self) -> Result<String, String> {
+		self.expect('"')?;
+		let mut result = String::new();
+		loop {
+			let character = self.peek().ok_or("Unterminated string")?;
+			self.position += 1;
+			match character {
+				'"' => return Ok(result),
+				'\\' => {
+					let escaped = self.peek().ok_or("Unterminated string")?;
+					self.position += 1;
+					match escaped {
+						'"' => result.push('"'),
+						'\\' => result.push('\\'),
+						'/' => result.push('/'),
+						'b' => result.push('\u{8}'),
+						'f' => result.push('\u{c}'),
+						'n' => result.push('\n'),
+						'r' => result.push('\r'),
+						't' => result.push('\t'),
+						'u' => {
+							let mut code = self.parse_code_unit()?;
+							// characters outside of the basic plane are escaped as a UTF-16 surrogate pair
+							if (0xd800..0xdc00).contains(&code) {
+								if self.characters.get(self.position..self.position + 2) != Some(&['\\', 'u']) {
+									return Err(format!("Unpaired surrogate at position {}", self.position));
+								}
+								self.position += 2;
+								let low = self.parse_code_unit()?;
+								if !(0xdc00..0xe000).contains(&low) {
+									return Err(format!("Unpaired surrogate at position {}", self.position));
+								}
+								code = 0x10000 + ((code - 0xd800) << 10) + (low - 0xdc00);
+							}
+							result.push(char::from_u32(code).ok_or(format!("Unpaired surrogate at position {}", self.position))?);
+						}
+						_ => return Err(format!("Invalid escape \\{} at position {}", escaped, self.position)),
+					}
+				}
+				character if (character as u32) < 0x20 => return Err(format!("Unescaped control character at position {}", self.position - 1)),
+				character => result.push(character),
+			}
+		}
+	}
+
+	fn parse_array(&mut self) -> Result<JsonValue, String> {
+		self.expect('[')?;
+		let mut values = Vec::new();
+		self.skip_whitespace();
+		if self.peek() == Some(']') {
+			self.position += 1;
+			return Ok(JsonValue::Array(values));
+		}
+		loop {
+			values.push(self.parse_value()?);
+			self.skip_whitespace();
+			match self.peek() {
+				Some(',') => self.position += 1,
+				Some(']') => {
+					self.position += 1;
+					return Ok(JsonValue::Array(values));
+				}
+				_ => return Err(format!("Expected ',' or ']' at position {}", self.position)),
+			}
+		}
+	}
+
+	fn parse_object(&mut self) -> Result<JsonValue, String> {
+		self.expect('{')?;
+		let mut entries = Vec::new();
+		self.skip_whitespace();
+		if self.peek() == Some('}') {
+			self.position += 1;
+			return Ok(JsonValue::Object(entries));
+		}
+		loop {
+			self.skip_whitespace();
+			let key = self.parse_string()?;
+			self.expect(':')?;
+			entries.push((key, self.parse_value()?));
+			self.skip_whitespace();
+			match self.peek() {
+				Some(',') => self.position += 1,
+				Some('}') => {
+					self.position += 1;
+					return Ok(JsonValue::Object(entries));
+				}
+				_ => return Err(format!("Expected ',' or '}}' at position {}", self.position)),
+			}
+		}
+	}
+}
+
+#[cfg(test)]
+mod tests {
+	use super::*;
+
+	#[test]
+	fn round_trip() {
+		let text = r#"{"name":"a\"b\\c\n\u0001ü😀","values":[0,-1.5,2e-7,true,false,null],"empty":{}}"#;
+		let value = parse(text).unwrap();
+		assert_eq!(value.get("name").and_then(JsonValue::as_str), Some("a\"b\\c\n\u{1}ü😀"));
+		assert_eq!(parse(&value.stringify()).unwrap(), value);
+	}
+
+	#[test]
+	fn numbers_follow_the_grammar() {
+		for (text, number) in [("0", 0.0), ("-0.5", -0.5), ("10", 10.0), ("1e3", 1000.0), ("1.25E-2", 0.0125), ("2e+1", 20.0)] {
+			assert_eq!(parse(text).unwrap(), JsonValue::Number(number), "{}", text);
+		}
+		for text in ["01", "-01", "+1", ".5", "1.", "1e", "1e+", "-", "--1", "1.2.3", "0x10", "Infinity"] {
+			assert!(parse(text).is_err(), "{}", text);
+		}
+	}
+
+	#[test]
+	fn surrogate_pairs_are_combined() {
+		assert_eq!(parse(r#""\ud83d\ude00""#).unwrap(), JsonValue::String("😀".to_string()));
+		for text in [r#""\ud83d""#, r#""\ud83dx""#, r#""\ude00""#, r#""\ud83dA""#, r#""\u12g4""#, r#""\u+123""#] {
+			assert!(parse(text).is_err(), "{}", text);
+		}
+	}
+
+	#[test]
+	fn malformed_documents_fail() {
+		for text in ["", "[1,]", "[1 2]", "{\"a\" 1}", "{\"a\":1,}", "{a:1}", "\"tab\there\"", "[1]x", "tru", "\u{a0}1"] {
+			assert!(parse(text).is_err(), "{:?}", text);
+		}
+	}
+}
\ No newline at end of file
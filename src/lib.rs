@@ -1,25 +1,150 @@
 #![allow(clippy::needless_return)]
 
+mod acoustics;
+mod analysis;
+mod binary;
+mod complex;
+mod csv;
+mod dtmf;
+mod effects;
+mod expression;
+mod filters;
+mod input_analyzer;
+mod json;
+mod measurement;
+mod mixing;
+mod modulation;
+mod precision;
+mod progress;
+mod project;
+mod quantization;
+mod ranging;
+mod reconstruction;
+mod resampling;
 mod signals;
+#[cfg(feature = "simd")]
+mod simd;
+mod streaming;
+#[cfg(all(feature = "threads", not(target_arch = "wasm32")))]
+mod threads;
+mod transforms;
+mod wav;
+mod wavelets;
+mod windows;
 
+use std::hash::{DefaultHasher, Hash, Hasher};
+use std::sync::Mutex;
+
+use rand::{rngs::StdRng, Rng, SeedableRng};
+#[cfg(feature = "wasm")]
 use wasm_bindgen::prelude::*;
 
+use precision::{Sample, Samples};
+
+#[cfg(feature = "wasm")]
 #[wasm_bindgen]
 extern {
 	#[wasm_bindgen(js_namespace = console)]
 	pub fn log(s: &str);
+
+	/// Signal description for `add_signal`, an object or its JSON text
+	#[wasm_bindgen(typescript_type = "object | string")]
+	pub type SignalConfig;
+
+	#[wasm_bindgen(js_namespace = JSON, js_name = stringify, catch)]
+	fn stringify(value: &JsValue) -> Result<JsValue, JsValue>;
 }
 
-#[wasm_bindgen]
+/// Signal description for `add_signal` as JSON text
+#[cfg(not(feature = "wasm"))]
+pub type SignalConfig = str;
+
+/// JSON text of `config`, strings are taken as they are and objects go through JSON.stringify
+#[cfg(feature = "wasm")]
+fn config_text(config: &SignalConfig) -> Result<String, ApiError> {
+	if let Some(text) = config.as_string() {
+		return Ok(text);
+	}
+	return stringify(config).ok().and_then(|text| text.as_string()).ok_or_else(|| api_error("Signal config can't be converted to JSON"));
+}
+
+#[cfg(not(feature = "wasm"))]
+fn config_text(config: &SignalConfig) -> Result<String, ApiError> {
+	return Ok(config.to_string());
+}
+
+pub use acoustics::{BandLevel, LevelMeters};
+pub use analysis::{DistributionFit, FrequencyMeasurement, Histogram, PeriodExtraction, SignalComparison, SignalStatistics, TimeInterval, TransferFunctionEstimate};
+pub use dtmf::DtmfDigit;
+pub use filters::{BiquadFilter, ComplexValues, Filter, PoleZeroPlot};
+pub use input_analyzer::InputAnalyzer;
+pub use progress::ProgressCallback;
+pub use quantization::Quantization;
+pub use ranging::EchoSimulation;
+pub use reconstruction::AliasingDemonstration;
+pub use streaming::StreamingGenerator;
+pub use transforms::{bin_to_frequency, fft_frequencies, frequency_to_bin, PeakEstimate, Spectrogram, Spectrum};
+pub use wavelets::{WaveletDenoising, WaveletTransform};
+
+/// Error of the public API, an exception in JS and plain text without the wasm layer
+#[cfg(feature = "wasm")]
+pub type ApiError = JsError;
+#[cfg(not(feature = "wasm"))]
+pub type ApiError = String;
+
+#[cfg(feature = "wasm")]
+pub(crate) fn api_error(message: &str) -> ApiError {
+	return JsError::new(message);
+}
+
+#[cfg(not(feature = "wasm"))]
+pub(crate) fn api_error(message: &str) -> ApiError {
+	return message.to_string();
+}
+
+#[cfg_attr(feature = "wasm", wasm_bindgen)]
 pub struct SignalProcessor {
 	/// Sampling frequency in Hz
 	pub sampling_frequency: f64,
 	/// Starting time offset in s
 	pub starting_time: f64,
-	signals: Vec<Box<dyn signals::CalculableSignal>>
+	signals: Vec<Box<dyn signals::CalculableSignal>>,
+	/// Id of each signal in `signals`, which stays the same when other signals are removed
+	signal_ids: Vec<u32>,
+	/// Id given to the next added signal, ids are never reused
+	next_signal_id: u32,
+	/// Ids of signals left out of the combined signal
+	disabled_ids: Vec<u32>,
+	/// Signal id, key and value of user strings attached to signals, like their names
+	metadata: Vec<(u32, String, String)>,
+	modulations: Vec<modulation::Modulation>,
+	/// RMS of random sampling clock jitter in s, 0 for ideal sampling
+	jitter_rms: f64,
+	/// How signals of different durations are combined
+	length_policy: mixing::LengthPolicy,
+	/// How values of all signals are combined into the output
+	combine_mode: mixing::CombineMode,
+	/// Seed of all random values, None draws new values on every evaluation
+	seed: Option<u64>,
+	/// Signal is evaluated at this many times the sampling frequency, lowpass filtered and decimated, 1 disables it
+	oversampling: usize,
+	/// Samples in each chunk of `next_chunk`, 0 before `begin_stream`
+	stream_chunk: usize,
+	/// Samples already returned by `next_chunk`
+	stream_position: usize,
+	/// Values of the whole signal of components that can't be computed a chunk at a time, filled by the first chunk
+	stream_whole: WholeValues,
+	/// Id, key from `get_cache_key` and values of the last evaluation of each signal
+	cache: Mutex<Vec<(u32, u64, Samples)>>,
+	/// Signals are evaluated and combined in f32 instead of f64
+	single_precision: bool,
 }
 
-#[wasm_bindgen]
+/// Ids and values at every point of the whole signal of components that can't be computed at only some of the points
+/// Kept as f64, which holds values computed in single precision exactly
+type WholeValues = Vec<(u32, Vec<f64>)>;
+
+#[cfg_attr(feature = "wasm", wasm_bindgen)]
 pub struct CoordPair {
 	/// Time in seconds
 	pub x: f64,
@@ -27,74 +152,1994 @@ pub struct CoordPair {
 	pub y: f64,
 }
 
-#[wasm_bindgen]
+/// Signal as two parallel arrays, which cross into JS as whole typed arrays instead of an object per sample
+#[cfg_attr(feature = "wasm", wasm_bindgen)]
+pub struct SignalArrays {
+	/// Sampling points in s
+	times: Samples,
+	values: Samples,
+}
+
+#[cfg_attr(feature = "wasm", wasm_bindgen)]
+impl SignalArrays {
+	/// Copy of sampling points in s as a Float64Array
+	pub fn times(&self) -> Vec<f64> {
+		return self.times.to_f64();
+	}
+
+	/// Copy of signal values as a Float64Array
+	pub fn values(&self) -> Vec<f64> {
+		return self.values.to_f64();
+	}
+
+	/// Sampling points in s as a Float32Array, half the size of `times` but only about 7 significant digits
+	/// Kept as f32 in single precision mode, rounded from f64 otherwise
+	pub fn times_f32(&self) -> Vec<f32> {
+		return self.times.to_f32();
+	}
+
+	/// Signal values as a Float32Array, half the size of `values`
+	/// Computed in f32 in single precision mode, rounded from f64 otherwise
+	pub fn values_f32(&self) -> Vec<f32> {
+		return self.values.to_f32();
+	}
+
+	pub fn len(&self) -> usize {
+		return self.times.len();
+	}
+
+	pub fn is_empty(&self) -> bool {
+		return self.len() == 0;
+	}
+
+	/// Address of sampling points in WASM memory, `new Float64Array(memory.buffer, times_ptr(), len())` views them without copying
+	/// The view is valid until this object is freed or WASM memory grows. Null in single precision mode, see `times_f32_ptr`
+	pub fn times_ptr(&self) -> *const f64 {
+		return match &self.times {
+			Samples::Double(times) => times.as_ptr(),
+			Samples::Single(_) => std::ptr::null(),
+		};
+	}
+
+	/// Address of signal values in WASM memory, viewed the same way as `times_ptr`
+	pub fn values_ptr(&self) -> *const f64 {
+		return match &self.values {
+			Samples::Double(values) => values.as_ptr(),
+			Samples::Single(_) => std::ptr::null(),
+		};
+	}
+
+	/// Address of sampling points in single precision mode, viewed with a Float32Array like `times_ptr`. Null otherwise
+	pub fn times_f32_ptr(&self) -> *const f32 {
+		return match &self.times {
+			Samples::Double(_) => std::ptr::null(),
+			Samples::Single(times) => times.as_ptr(),
+		};
+	}
+
+	/// Address of signal values in single precision mode, viewed with a Float32Array like `times_ptr`. Null otherwise
+	pub fn values_f32_ptr(&self) -> *const f32 {
+		return match &self.values {
+			Samples::Double(_) => std::ptr::null(),
+			Samples::Single(values) => values.as_ptr(),
+		};
+	}
+}
+
+#[cfg_attr(feature = "wasm", wasm_bindgen)]
+impl SignalProcessor {
+	/// Fails for sampling frequencies that aren't positive or starting times that aren't finite
+	pub fn new(sampling_frequency: f64, starting_time: f64) -> Result<SignalProcessor, ApiError> {
+		return Self::with_sampling(sampling_frequency, starting_time).map_err(|error| api_error(&error));
+	}
+
+	/// Restores a processor from JSON created by `save_project`, including projects saved by older versions
+	pub fn load_project(json: &str) -> Result<SignalProcessor, ApiError> {
+		return Self::from_project(json).map_err(|error| api_error(&error));
+	}
+
+	/// Serializes sampling settings, signals and modulations into a versioned JSON project
+	pub fn save_project(&self) -> String {
+		return project::save(self);
+	}
+
+	/// Whole configuration as JSON, the same as `save_project`, for saving a scene, sharing it or restoring it after a reload
+	pub fn to_json(&self) -> String {
+		return self.save_project();
+	}
+
+	/// Restores a processor from `to_json` output, the same as `load_project`
+	pub fn from_json(json: &str) -> Result<SignalProcessor, ApiError> {
+		return Self::load_project(json);
+	}
+
+	/// Signal as a mono WAV file at the sampling frequency rounded to whole Hz
+	/// `bit_depth` 8, 16 or 24 gives integer PCM clipping values outside of -1..1, 32 gives floats
+	pub fn export_wav(&self, bit_depth: u16) -> Result<Vec<u8>, ApiError> {
+		return self.encode_wav(bit_depth).map_err(|error| api_error(&error));
+	}
+
+	/// Adds the contents of a WAV file as a signal starting at `starting_time` and returns its id
+	/// Channels are averaged, and a file sampled at another rate is resampled to the sampling frequency
+	pub fn import_wav(&mut self, bytes: &[u8]) -> Result<u32, ApiError> {
+		let (samples, sample_rate) = wav::decode(bytes).map_err(|error| api_error(&format!("Could not import WAV: {}", error)))?;
+		let samples = if sample_rate == self.sampling_frequency { samples } else { resampling::resample_bandlimited(&samples, sample_rate, self.sampling_frequency) };
+		return Ok(self.add_sampled(self.starting_time, samples));
+	}
+
+	/// Signal as CSV text with a "time,value" header and a row for every sample
+	pub fn export_csv(&self) -> String {
+		let (times, values): (Vec<f64>, Vec<f64>) = self.compose_signal().into_iter().map(|point| (point.x, point.y)).unzip();
+		return csv::format(&times, &values);
+	}
+
+	/// Adds signal from CSV rows of time in s and value, like the ones of `export_csv`, and returns its id
+	/// Rows have to be evenly spaced in time, their spacing sets the sample rate. Values between rows are interpolated linearly
+	pub fn import_csv(&mut self, text: &str) -> Result<u32, ApiError> {
+		let (times, values) = csv::parse(text).map_err(|error| api_error(&format!("Could not import CSV: {}", error)))?;
+		if times.len() < 2 || times[times.len() - 1] <= times[0] {
+			return Err(api_error("CSV needs at least two rows with increasing times"));
+		}
+		let sample_rate = (times.len() - 1) as f64 / (times[times.len() - 1] - times[0]);
+		return Ok(self.insert_sampled(sample_rate, times[0], values, "linear"));
+	}
+
+	/// Signal in a compact binary format keeping its sampling frequency and start time, which `import_binary` restores losslessly
+	pub fn export_binary(&self) -> Vec<u8> {
+		let signal = self.compose_signal();
+		return binary::encode(&binary::BinarySignal {
+			sampling_frequency: self.sampling_frequency,
+			start_time: signal.first().map_or(self.starting_time, |point| point.x),
+			samples: signal.into_iter().map(|point| point.y).collect(),
+		});
+	}
+
+	/// Adds signal saved by `export_binary`, at its own sampling frequency and start time, and returns its id
+	pub fn import_binary(&mut self, bytes: &[u8]) -> Result<u32, ApiError> {
+		let signal = binary::decode(bytes).map_err(|error| api_error(&format!("Could not import binary signal: {}", error)))?;
+		return Ok(self.insert_sampled(signal.sampling_frequency, signal.start_time, signal.samples, "nearest"));
+	}
+
+	/// Adds signal described by object `config` and returns its id, for example {type: "sine", signal_freq: 5, amplitude: 2}
+	/// Types and parameter names are the same as in `describe_signal`, "samples" and "text" give stored data. Parameters not listed keep defaults
+	/// The object may also be passed as JSON text
+	pub fn add_signal(&mut self, config: &SignalConfig) -> Result<u32, ApiError> {
+		let config = json::parse(&config_text(config)?).map_err(|error| api_error(&format!("Invalid signal config: {}", error)))?;
+		let signal = project::signal_from_config(&config).map_err(|error| api_error(&format!("Could not add signal: {}", error)))?;
+		return Ok(self.insert_signal(signal));
+	}
+
+	pub fn add_sine(&mut self, signal_freq: f64, duration: f64, start_offset: f64, amplitude: f64, phase_shift: f64) -> Result<u32, ApiError> {
+		return self.push_signal("sine", "", &[("signal_freq", signal_freq), ("duration", duration), ("start_offset", start_offset), ("amplitude", amplitude), ("phase_shift", phase_shift)]);
+	}
+
+	pub fn add_half_wave_rectified_sine(&mut self, signal_freq: f64, duration: f64, start_offset: f64, amplitude: f64, phase_shift: f64) -> Result<u32, ApiError> {
+		return self.push_signal("half_wave_rectified_sine", "", &[("signal_freq", signal_freq), ("duration", duration), ("start_offset", start_offset), ("amplitude", amplitude), ("phase_shift", phase_shift)]);
+	}
+
+	pub fn add_full_wave_rectified_sine(&mut self, signal_freq: f64, duration: f64, start_offset: f64, amplitude: f64, phase_shift: f64) -> Result<u32, ApiError> {
+		return self.push_signal("full_wave_rectified_sine", "", &[("signal_freq", signal_freq), ("duration", duration), ("start_offset", start_offset), ("amplitude", amplitude), ("phase_shift", phase_shift)]);
+	}
+
+	/// Sine multiplied by exp(-t / `decay_constant`), where t is time since `start_offset`
+	pub fn add_damped_sine(&mut self, signal_freq: f64, decay_constant: f64, duration: f64, start_offset: f64, amplitude: f64, phase_shift: f64) -> Result<u32, ApiError> {
+		return self.push_signal("damped_sine", "", &[("signal_freq", signal_freq), ("decay_constant", decay_constant), ("duration", duration), ("start_offset", start_offset), ("amplitude", amplitude), ("phase_shift", phase_shift)]);
+	}
+
+	/// Square wave between -`amplitude` and `amplitude` built from its first `harmonic_count` odd harmonics, which shows Gibbs ringing
+	pub fn add_fourier_square(&mut self, signal_freq: f64, harmonic_count: u32, duration: f64, start_offset: f64, amplitude: f64) -> Result<u32, ApiError> {
+		return self.push_signal("fourier_square", "", &[("signal_freq", signal_freq), ("harmonic_count", harmonic_count as f64), ("duration", duration), ("start_offset", start_offset), ("amplitude", amplitude)]);
+	}
+
+	pub fn add_uniform_noise(&mut self, duration: f64, start_offset: f64, amplitude: f64) -> Result<u32, ApiError> {
+		return self.push_signal("uniform_noise", "", &[("duration", duration), ("start_offset", start_offset), ("amplitude", amplitude)]);
+	}
+
+	pub fn add_normal_noise(&mut self, duration: f64, start_offset: f64, amplitude: f64) -> Result<u32, ApiError> {
+		return self.push_signal("normal_noise", "", &[("duration", duration), ("start_offset", start_offset), ("amplitude", amplitude)]);
+	}
+
+	/// Noise with power falling by 3 dB per octave, staying between -`amplitude` and `amplitude`
+	pub fn add_pink_noise(&mut self, duration: f64, start_offset: f64, amplitude: f64) -> Result<u32, ApiError> {
+		return self.push_signal("pink_noise", "", &[("duration", duration), ("start_offset", start_offset), ("amplitude", amplitude)]);
+	}
+
+	/// Random walk with power falling by 6 dB per octave, reflected to stay between -`amplitude` and `amplitude`
+	pub fn add_brownian_noise(&mut self, duration: f64, start_offset: f64, amplitude: f64) -> Result<u32, ApiError> {
+		return self.push_signal("brownian_noise", "", &[("duration", duration), ("start_offset", start_offset), ("amplitude", amplitude)]);
+	}
+
+	/// Salt and pepper noise - spikes of `positive_amplitude` or -`negative_amplitude` at random samples with total `probability`, 0 elsewhere
+	pub fn add_impulsive_noise(&mut self, probability: f64, positive_amplitude: f64, negative_amplitude: f64, duration: f64, start_offset: f64) -> Result<u32, ApiError> {
+		return self.push_signal("impulsive_noise", "", &[("probability", probability), ("positive_amplitude", positive_amplitude), ("negative_amplitude", negative_amplitude), ("duration", duration), ("start_offset", start_offset)]);
+	}
+
+	pub fn add_rectangular(&mut self, signal_freq: f64, duration: f64, start_offset: f64, amplitude: f64, duty_cycle: f64) -> Result<u32, ApiError> {
+		return self.push_signal("rectangular", "", &[("signal_freq", signal_freq), ("duration", duration), ("start_offset", start_offset), ("amplitude", amplitude), ("duty_cycle", duty_cycle)]);
+	}
+
+	pub fn add_symmetric_rectangular(&mut self, signal_freq: f64, duration: f64, start_offset: f64, amplitude: f64, duty_cycle: f64) -> Result<u32, ApiError> {
+		return self.push_signal("symmetric_rectangular", "", &[("signal_freq", signal_freq), ("duration", duration), ("start_offset", start_offset), ("amplitude", amplitude), ("duty_cycle", duty_cycle)]);
+	}
+
+	pub fn add_triangular(&mut self, signal_freq: f64, duration: f64, start_offset: f64, amplitude: f64, duty_cycle: f64) -> Result<u32, ApiError> {
+		return self.push_signal("triangular", "", &[("signal_freq", signal_freq), ("duration", duration), ("start_offset", start_offset), ("amplitude", amplitude), ("duty_cycle", duty_cycle)]);
+	}
+
+	pub fn add_unit_jump(&mut self, flip_offset: f64, duration: f64, start_offset: f64, amplitude: f64) -> Result<u32, ApiError> {
+		return self.push_signal("unit_jump", "", &[("flip_offset", flip_offset), ("duration", duration), ("start_offset", start_offset), ("amplitude", amplitude)]);
+	}
+
+	pub fn add_unit_pulse(&mut self, time_offset: f64, duration: f64, start_offset: f64, amplitude: f64) -> Result<u32, ApiError> {
+		return self.push_signal("unit_pulse", "", &[("time_offset", time_offset), ("duration", duration), ("start_offset", start_offset), ("amplitude", amplitude)]);
+	}
+
+	/// Pulses of `amplitude` every `period` s starting at `start_offset`, each one at the sampling point closest to it
+	pub fn add_impulse_train(&mut self, period: f64, duration: f64, start_offset: f64, amplitude: f64) -> Result<u32, ApiError> {
+		return self.push_signal("impulse_train", "", &[("period", period), ("duration", duration), ("start_offset", start_offset), ("amplitude", amplitude)]);
+	}
+
+	/// Gaussian bell with peak of `amplitude` at `center` s after `start_offset` and standard deviation of `width` s
+	pub fn add_gaussian_pulse(&mut self, center: f64, width: f64, duration: f64, start_offset: f64, amplitude: f64) -> Result<u32, ApiError> {
+		return self.push_signal("gaussian_pulse", "", &[("center", center), ("width", width), ("duration", duration), ("start_offset", start_offset), ("amplitude", amplitude)]);
+	}
+
+	/// sin(x) / x pulse peaking at `center` s after `start_offset`, with a flat spectrum from 0 to `bandwidth` Hz
+	pub fn add_sinc(&mut self, center: f64, bandwidth: f64, duration: f64, start_offset: f64, amplitude: f64) -> Result<u32, ApiError> {
+		return self.push_signal("sinc_pulse", "", &[("center", center), ("bandwidth", bandwidth), ("duration", duration), ("start_offset", start_offset), ("amplitude", amplitude)]);
+	}
+
+	/// Line rising by `rate` per second from 0 at `start_offset`
+	pub fn add_ramp(&mut self, rate: f64, duration: f64, start_offset: f64) -> Result<u32, ApiError> {
+		return self.push_signal("ramp", "", &[("rate", rate), ("duration", duration), ("start_offset", start_offset)]);
+	}
+
+	/// Steps of `step_height` every `step_duration` s, starting from 0 at `start_offset`
+	pub fn add_staircase(&mut self, step_height: f64, step_duration: f64, duration: f64, start_offset: f64) -> Result<u32, ApiError> {
+		return self.push_signal("staircase", "", &[("step_height", step_height), ("step_duration", step_duration), ("duration", duration), ("start_offset", start_offset)]);
+	}
+
+	pub fn add_unit_noise(&mut self, probability: f64, duration: f64, start_offset: f64, amplitude: f64) -> Result<u32, ApiError> {
+		return self.push_signal("unit_noise", "", &[("probability", probability), ("duration", duration), ("start_offset", start_offset), ("amplitude", amplitude)]);
+	}
+
+	/// Adds signal given by formula `expression` of time t in s, like "2*sin(2*pi*5*t) + 0.3*t"
+	/// Supports + - * / ^, parentheses, constants pi and e and functions sin, cos, tan, exp, ln, log10, sqrt, abs, floor, ceil and sign
+	pub fn add_expression_signal(&mut self, expression: &str, duration: f64, start_offset: f64) -> Result<u32, ApiError> {
+		signals::validate_parameter("duration", duration).map_err(|error| api_error(&error))?;
+		let signal = signals::ExpressionSignal::new(expression.to_string(), duration, start_offset).map_err(|error| api_error(&format!("Invalid expression: {}", error)))?;
+		return Ok(self.insert_signal(Box::new(signal)));
+	}
+
+	/// Adds recorded `samples` taken at `sample_rate` Hz, with the first one at `start_offset` s
+	/// Values between samples are found with `interpolation`: "nearest", "linear" or "cubic"
+	pub fn add_custom_samples(&mut self, samples: Vec<f64>, sample_rate: f64, start_offset: f64, interpolation: &str) -> Result<u32, ApiError> {
+		if !resampling::is_method(interpolation) {
+			return Err(api_error(&format!("Unknown interpolation {}", interpolation)));
+		}
+		signals::validate_parameter("sampling_frequency", sample_rate).map_err(|error| api_error(&error))?;
+		return Ok(self.insert_signal(Box::new(signals::SampledSignal::new(sample_rate, start_offset, samples, interpolation.to_string()))));
+	}
+
+	/// Keys `text` as Morse code tone of `tone_freq` Hz at `wpm` words per minute
+	pub fn add_morse(&mut self, text: &str, wpm: f64, tone_freq: f64, amplitude: f64) -> Result<u32, ApiError> {
+		return self.push_signal("morse", text, &[("words_per_minute", wpm), ("tone_freq", tone_freq), ("amplitude", amplitude)]);
+	}
+
+	/// Combination of all enabled signals except the ones serving only as a modulation source, summed unless another combine mode is set
+	/// Signals contribute 0 outside of their duration, or repeat or get cut off according to the length policy
+	/// Fails when there are no signals to combine
+	pub fn get_signal(&self) -> Result<Vec<CoordPair>, ApiError> {
+		if self.signals.is_empty() {
+			return Err(api_error("There are no signals to combine"));
+		}
+		return Ok(self.compose_signal());
+	}
+
+	/// Part of `get_signal` from `t_start` to `t_end` in s, computing only the sampling points in between
+	/// Signals depending on neighbouring points, like pulses, noise walks or frequency modulated ones, are still computed whole
+	pub fn get_signal_range(&self, t_start: f64, t_end: f64) -> Vec<CoordPair> {
+		return self.compose_part(self.get_output_index_range_between(t_start, t_end), &mut Vec::new());
+	}
+
+	/// `get_signal_range` thinned to at most `max_points` evenly spaced sampling points, for drawing a zoomed out chart
+	/// Only the kept points are computed, without jitter and oversampling, so content above their rate aliases
+	pub fn get_signal_range_decimated(&self, t_start: f64, t_end: f64, max_points: usize) -> Vec<CoordPair> {
+		let part = self.get_output_index_range_between(t_start, t_end);
+		let step = part.len().div_ceil(max_points.max(1)).max(1);
+		let range = self.get_output_index_range();
+		let positions: Vec<usize> = part.step_by(step).map(|index| index - range.start).collect();
+		let points: Vec<f64> = positions.iter().map(|position| self.get_sampling_point(range.start + position)).collect();
+		let mut whole = Vec::new();
+		self.add_whole_values(|| self.get_output_points(), &mut whole);
+		let values: Vec<f64> = if self.single_precision {
+			self.evaluate_combined_within::<f32>(&points, Some((&positions, &whole))).into_iter().map(f64::from).collect()
+		} else {
+			self.evaluate_combined_within(&points, Some((&positions, &whole)))
+		};
+		return points.into_iter().zip(values).map(|(x, y)| CoordPair { x, y }).collect();
+	}
+
+	/// Starts producing the combined signal in chunks of `chunk_samples` values with `next_chunk`, from its first sample
+	pub fn begin_stream(&mut self, chunk_samples: usize) -> Result<(), ApiError> {
+		if chunk_samples == 0 {
+			return Err(api_error("Chunks need at least one sample"));
+		}
+		self.stream_chunk = chunk_samples;
+		self.stream_position = 0;
+		self.stream_whole.clear();
+		return Ok(());
+	}
+
+	/// Next chunk of values of `get_signal`, shorter at the end and empty once the whole signal was returned
+	/// Only the chunk is computed, so long signals can be rendered or played without holding all of them. Signals depending
+	/// on neighbouring points, like pulses, noise walks or frequency modulated ones, are computed whole with the first chunk
+	pub fn next_chunk(&mut self) -> Result<Vec<f64>, ApiError> {
+		if self.single_precision {
+			return Ok(self.next_chunk_values::<f32>()?.into_iter().map(f64::from).collect());
+		}
+		return self.next_chunk_values();
+	}
+
+	/// `next_chunk` as a Float32Array, computed in f32 in single precision mode and rounded from f64 otherwise
+	pub fn next_chunk_f32(&mut self) -> Result<Vec<f32>, ApiError> {
+		if self.single_precision {
+			return self.next_chunk_values();
+		}
+		return Ok(self.next_chunk_values::<f64>()?.into_iter().map(|value| value as f32).collect());
+	}
+
+	/// Same as `get_signal`, but with times and values in separate arrays, which is much faster to pass to JS for long signals
+	/// In single precision mode both arrays are kept as f32, for `SignalArrays::values_f32` and `SignalArrays::values_f32_ptr`
+	pub fn get_signal_arrays(&self) -> SignalArrays {
+		let range = self.get_output_index_range();
+		let times = range.clone().map(|index| self.get_sampling_point(index));
+		if self.single_precision {
+			return SignalArrays {
+				times: Samples::Single(times.map(|time| time as f32).collect()),
+				values: Samples::Single(self.compose_values(range, &mut Vec::new())),
+			};
+		}
+		return SignalArrays {
+			times: Samples::Double(times.collect()),
+			values: Samples::Double(self.compose_values(range, &mut Vec::new())),
+		};
+	}
+
+	/// Values of signal `id` alone on the same sampling points as `get_signal`, with modulations targeting it
+	/// Jitter and oversampling only apply to the combined signal
+	pub fn get_signal_by_id(&self, id: u32) -> Result<Vec<CoordPair>, ApiError> {
+		let index = self.index_of(id)?;
+		return Ok(self.calculate_signal_at(index, &self.get_sampling_points()));
+	}
+
+	/// Simulates sampling clock jitter - every sampling instant is moved by normally distributed time with `jitter_rms` s RMS
+	/// Returned times stay on the nominal grid. 0 restores ideal sampling
+	pub fn set_jitter(&mut self, jitter_rms: f64) {
+		self.jitter_rms = jitter_rms;
+	}
+
+	/// Makes noise signals and jitter give the same values on every evaluation and in every session with the same `seed`
+	pub fn set_seed(&mut self, seed: u64) {
+		self.seed = Some(seed);
+	}
+
+	/// Goes back to drawing new random values on every evaluation
+	pub fn clear_seed(&mut self) {
+		self.seed = None;
+	}
+
+	/// Evaluates and combines signals in f32 instead of f64, which halves the memory taken by remembered signals and returned arrays
+	/// Meant for plots, together with the f32 getters like `SignalArrays::values_f32` and `next_chunk_f32` which then need no conversion
+	/// Sine, triangle and rectified sine signals have f32 formulas, the rest and modulated signals are computed in f64 and rounded
+	pub fn set_single_precision(&mut self, enabled: bool) {
+		self.single_precision = enabled;
+		self.clear_cache();
+	}
+
+	/// Evaluates signals at `factor` times the sampling frequency and filters out content above Nyquist frequency before decimating
+	/// Reduces aliasing of signals with sharp edges. 1 disables oversampling
+	pub fn set_oversampling(&mut self, factor: usize) {
+		self.oversampling = factor.max(1);
+	}
+
+	/// Calculates `operation` ("add", "subtract", "multiply" or "divide") on signals `id_a` and `id_b`
+	/// and stores the sampled result as a new signal. Returns the id of the new signal
+	/// Signals of different durations are combined according to the length policy
+	pub fn derive_signal(&mut self, id_a: u32, id_b: u32, operation: &str) -> Result<u32, ApiError> {
+		let index_a = self.index_of(id_a)?;
+		let index_b = self.index_of(id_b)?;
+		let (points, values_a, values_b) = self.get_aligned_pair(index_a, index_b);
+		let samples = values_a.into_iter().zip(values_b).map(|(a, b)| signals::apply_operation(operation, a, b)).collect::<Option<Vec<f64>>>()
+			.ok_or_else(|| api_error(&format!("Unknown operation {}", operation)))?;
+		let start = points.first().copied().unwrap_or(self.starting_time);
+		return Ok(self.insert_sampled(self.sampling_frequency, start, samples, "nearest"));
+	}
+
+	pub fn signal_count(&self) -> usize {
+		return self.signals.len();
+	}
+
+	/// Frees values of signals remembered from previous evaluations, which are otherwise reused while the signals don't change
+	pub fn clear_cache(&self) {
+		self.cache.lock().unwrap().clear();
+	}
+
+	/// Ids of all signals in the order they were added
+	pub fn get_signal_ids(&self) -> Vec<u32> {
+		return self.signal_ids.clone();
+	}
+
+	/// Removes signal `id` together with modulations it takes part in, ids of the other signals don't change
+	pub fn remove_signal(&mut self, id: u32) -> Result<(), ApiError> {
+		let index = self.index_of(id)?;
+		self.signals.remove(index);
+		self.signal_ids.remove(index);
+		self.disabled_ids.retain(|disabled_id| *disabled_id != id);
+		self.metadata.retain(|(signal_id, _, _)| *signal_id != id);
+		self.clear_cache();
+		self.modulations.retain(|modulation| {
+			return modulation.target_index != index && !matches!(modulation.source, modulation::ModulationSource::Oscillator { source_index, .. } if source_index == index);
+		});
+		for modulation in &mut self.modulations {
+			if modulation.target_index > index {
+				modulation.target_index -= 1;
+			}
+			if let modulation::ModulationSource::Oscillator { source_index, .. } = &mut modulation.source {
+				if *source_index > index {
+					*source_index -= 1;
+				}
+			}
+		}
+		return Ok(());
+	}
+
+	/// Removes all signals and modulations
+	pub fn clear_signals(&mut self) {
+		self.signals.clear();
+		self.signal_ids.clear();
+		self.disabled_ids.clear();
+		self.metadata.clear();
+		self.clear_cache();
+		self.modulations.clear();
+	}
+
+	/// Leaves signal `id` out of the combined signal while `enabled` is false, keeping all its parameters
+	/// A disabled signal still works as a modulation source and can be used on its own
+	pub fn set_signal_enabled(&mut self, id: u32, enabled: bool) -> Result<(), ApiError> {
+		self.index_of(id)?;
+		self.disabled_ids.retain(|disabled_id| *disabled_id != id);
+		if !enabled {
+			self.disabled_ids.push(id);
+		}
+		return Ok(());
+	}
+
+	pub fn is_signal_enabled(&self, id: u32) -> Result<bool, ApiError> {
+		self.index_of(id)?;
+		return Ok(!self.disabled_ids.contains(&id));
+	}
+
+	/// JSON object with type, parameters, id and metadata of signal `id`, in the same form as in saved projects
+	pub fn describe_signal(&self, id: u32) -> Result<String, ApiError> {
+		return Ok(project::save_stored_signal(self, self.index_of(id)?).stringify());
+	}
+
+	/// Name shown for signal `id` in the UI, stored as its "name" metadata
+	pub fn set_signal_name(&mut self, id: u32, name: &str) -> Result<(), ApiError> {
+		return self.set_signal_metadata(id, "name", name);
+	}
+
+	pub fn get_signal_name(&self, id: u32) -> Result<Option<String>, ApiError> {
+		return self.get_signal_metadata(id, "name");
+	}
+
+	/// Attaches string `value` under `key` to signal `id`, replacing the previous value of `key`
+	pub fn set_signal_metadata(&mut self, id: u32, key: &str, value: &str) -> Result<(), ApiError> {
+		self.index_of(id)?;
+		self.remove_signal_metadata(id, key);
+		self.metadata.push((id, key.to_string(), value.to_string()));
+		return Ok(());
+	}
+
+	pub fn get_signal_metadata(&self, id: u32, key: &str) -> Result<Option<String>, ApiError> {
+		self.index_of(id)?;
+		return Ok(self.metadata.iter().find(|(signal_id, metadata_key, _)| *signal_id == id && metadata_key == key).map(|(_, _, value)| value.clone()));
+	}
+
+	pub fn remove_signal_metadata(&mut self, id: u32, key: &str) {
+		self.metadata.retain(|(signal_id, metadata_key, _)| *signal_id != id || metadata_key != key);
+	}
+
+	/// Changes parameters of signal `id` to values from JSON object `parameters`, for example {"signal_freq": 440, "amplitude": 0.5}
+	/// Names are the same as in `describe_signal`, parameters not listed keep their values
+	/// Nothing changes when any of the parameters is invalid
+	pub fn update_signal(&mut self, id: u32, parameters: &str) -> Result<(), ApiError> {
+		let document = json::parse(parameters).map_err(|error| api_error(&format!("Invalid parameters: {}", error)))?;
+		let index = self.find_index(id).ok_or_else(|| api_error(&format!("There is no signal with id {}", id)))?;
+		let mut signal = self.signals[index].clone();
+		for (name, value) in document.as_object().ok_or_else(|| api_error("Parameters have to be a JSON object"))? {
+			let value = value.as_f64().ok_or_else(|| api_error(&format!("Parameter {} is not a number", name)))?;
+			signals::validate_parameter(name, value).map_err(|error| api_error(&error))?;
+			if !signal.set_parameter(name, value) {
+				return Err(api_error(&format!("Signal {} has no parameter {}", id, name)));
+			}
+		}
+		self.signals[index] = signal;
+		return Ok(());
+	}
+
+	/// Adds a signal calculated as the sum of signals `id_a` and `id_b`, returns its id
+	/// Unlike `derive_signal` the result is calculated from copies of both signals, so it isn't tied to the sampling frequency
+	/// Both operands are evaluated without modulations and are 0 outside of their own duration
+	pub fn add_signals(&mut self, id_a: u32, id_b: u32) -> Result<u32, ApiError> {
+		let index_a = self.index_of(id_a)?;
+		let index_b = self.index_of(id_b)?;
+		return Ok(self.add_composite(index_a, index_b, "add"));
+	}
+
+	/// Adds a signal calculated as signal `id_a` minus signal `id_b`, returns its id
+	pub fn subtract_signals(&mut self, id_a: u32, id_b: u32) -> Result<u32, ApiError> {
+		let index_a = self.index_of(id_a)?;
+		let index_b = self.index_of(id_b)?;
+		return Ok(self.add_composite(index_a, index_b, "subtract"));
+	}
+
+	/// Adds a signal calculated as the product of signals `id_a` and `id_b`, returns its id
+	pub fn multiply_signals(&mut self, id_a: u32, id_b: u32) -> Result<u32, ApiError> {
+		let index_a = self.index_of(id_a)?;
+		let index_b = self.index_of(id_b)?;
+		return Ok(self.add_composite(index_a, index_b, "multiply"));
+	}
+
+	/// Adds a signal calculated as signal `id_a` divided by signal `id_b`, returns its id
+	pub fn divide_signals(&mut self, id_a: u32, id_b: u32) -> Result<u32, ApiError> {
+		let index_a = self.index_of(id_a)?;
+		let index_b = self.index_of(id_b)?;
+		return Ok(self.add_composite(index_a, index_b, "divide"));
+	}
+
+	/// Chooses how signals of different durations are combined
+	/// "zero_pad" treats signals as zero outside of their duration, "truncate" keeps only the part where all signals exist,
+	/// "loop" repeats the shorter signal over the duration of the longer one
+	pub fn set_length_policy(&mut self, policy: &str) -> Result<(), ApiError> {
+		self.length_policy = mixing::LengthPolicy::from_name(policy).ok_or_else(|| api_error(&format!("Unknown length policy {}", policy)))?;
+		return Ok(());
+	}
+
+	/// Time interval where signal `id` is active among the sampling points, from its start offset until the end of its duration
+	pub fn get_signal_interval(&self, id: u32) -> Result<analysis::TimeInterval, ApiError> {
+		let index = self.index_of(id)?;
+		let (start, end) = self.get_signal_window(index);
+		return Ok(analysis::TimeInterval {
+			start,
+			end,
+		});
+	}
+
+	/// Chooses how values of signals are combined into the output: "sum", "multiply", "max" or "min"
+	/// Signals are 0 outside of their duration, so with "multiply" the output is silent wherever any signal is
+	pub fn set_combine_mode(&mut self, mode: &str) -> Result<(), ApiError> {
+		self.combine_mode = mixing::CombineMode::from_name(mode).ok_or_else(|| api_error(&format!("Unknown combine mode {}", mode)))?;
+		return Ok(());
+	}
+
+	/// Brings signals of `other` processor into this one
+	/// With `method` "signals" all signals and modulations are copied and evaluated at this processor's rate
+	/// With "nearest", "linear" or "cubic" the signal of `other` is rendered at its own rate and resampled to this one using given interpolation
+	pub fn import_from(&mut self, other: &SignalProcessor, method: &str) -> Result<(), ApiError> {
+		if method != "signals" && !resampling::is_method(method) {
+			return Err(api_error(&format!("Unknown import method {}", method)));
+		}
+		if method == "signals" {
+			let index_shift = self.signals.len();
+			for signal in &other.signals {
+				self.insert_signal(signal.clone_box());
+			}
+			self.modulations.extend(other.modulations.iter().cloned().map(|mut modulation| {
+				modulation.target_index += index_shift;
+				if let modulation::ModulationSource::Oscillator { source_index, .. } = &mut modulation.source {
+					*source_index += index_shift;
+				}
+				return modulation;
+			}));
+			return Ok(());
+		}
+		let other_points = other.get_sampling_points();
+		let other_end = other_points.last().map(|point| point + other.sampling_frequency.recip()).unwrap_or(other.starting_time);
+		let points = linspace_by_freq(other.starting_time, other_end, self.sampling_frequency);
+		let samples = resampling::resample(&other.get_signal_values(), other.starting_time, other.sampling_frequency, &points, method);
+		self.insert_sampled(self.sampling_frequency, other.starting_time, samples, "nearest");
+		return Ok(());
+	}
+
+	/// Makes signal `source_id` change `parameter` of signal `target_id` during evaluation
+	/// Parameter value becomes its set value plus `depth` times the value of the source signal
+	/// Source signal is evaluated without its own modulations
+	pub fn add_modulation(&mut self, source_id: u32, target_id: u32, parameter: &str, depth: f64) -> Result<(), ApiError> {
+		let source_index = self.index_of(source_id)?;
+		let target_index = self.index_of(target_id)?;
+		if modulation::get_parameter(self.signals[target_index].as_ref(), parameter).is_none() {
+			return Err(api_error(&format!("Signal {} has no parameter {}", target_id, parameter)));
+		}
+		self.modulations.push(modulation::Modulation {
+			target_index,
+			parameter: parameter.to_string(),
+			source: modulation::ModulationSource::Oscillator {
+				source_index,
+				depth,
+			},
+		});
+		return Ok(());
+	}
+
+	/// Makes `parameter` of signal `target_id` follow a breakpoint envelope instead of its set value
+	/// `times` in s from `starting_time` and `values` are paired by position, values between breakpoints are linearly interpolated
+	/// Oscillator modulations of the same parameter are added on top of the envelope
+	pub fn add_automation(&mut self, target_id: u32, parameter: &str, times: Vec<f64>, values: Vec<f64>) -> Result<(), ApiError> {
+		let target_index = self.index_of(target_id)?;
+		if modulation::get_parameter(self.signals[target_index].as_ref(), parameter).is_none() {
+			return Err(api_error(&format!("Signal {} has no parameter {}", target_id, parameter)));
+		}
+		if times.is_empty() || times.len() != values.len() {
+			return Err(api_error("Automation needs the same nonzero number of times and values"));
+		}
+		let mut breakpoints: Vec<(f64, f64)> = times.into_iter().zip(values).collect();
+		breakpoints.sort_by(|x, y| x.0.total_cmp(&y.0));
+		self.modulations.push(modulation::Modulation {
+			target_index,
+			parameter: parameter.to_string(),
+			source: modulation::ModulationSource::Envelope(breakpoints),
+		});
+		return Ok(());
+	}
+
+	pub fn clear_modulations(&mut self) {
+		self.modulations.clear();
+	}
+
+	/// Silences the signal where its level falls below `threshold`
+	pub fn apply_noise_gate(&self, threshold: f64, attack_ms: f64, release_ms: f64) -> Vec<CoordPair> {
+		return self.get_processed_signal(|samples| effects::noise_gate(samples, self.sampling_frequency, threshold, attack_ms, release_ms));
+	}
+
+	/// Compresses parts of the signal louder than `threshold_db` dBFS by `ratio`, which is at least 1
+	pub fn apply_compressor(&self, threshold_db: f64, ratio: f64, attack_ms: f64, release_ms: f64) -> Result<Vec<CoordPair>, ApiError> {
+		if ratio.is_nan() || ratio < 1.0 {
+			return Err(api_error(&format!("Compression ratio has to be at least 1, got {}", ratio)));
+		}
+		if attack_ms.is_nan() || attack_ms < 0.0 || release_ms.is_nan() || release_ms < 0.0 {
+			return Err(api_error(&format!("Attack and release can't be negative, got {} and {} ms", attack_ms, release_ms)));
+		}
+		return Ok(self.get_processed_signal(|samples| effects::compressor(samples, self.sampling_frequency, threshold_db, ratio, attack_ms, release_ms)));
+	}
+
+	/// Brick-wall limiter, no sample of the result exceeds `threshold_db` dBFS
+	pub fn apply_limiter(&self, threshold_db: f64, release_ms: f64) -> Vec<CoordPair> {
+		return self.get_processed_signal(|samples| effects::compressor(samples, self.sampling_frequency, threshold_db, f64::INFINITY, 0.0, release_ms));
+	}
+
+	/// Adds Schroeder reverb to the signal
+	/// `room_size` scales reflection delays, 1 being a medium room. `decay` is the reverb time in s, `mix` is between 0 (dry) and 1 (wet)
+	pub fn apply_reverb(&self, room_size: f64, decay: f64, mix: f64) -> Result<Vec<CoordPair>, ApiError> {
+		if room_size.is_nan() || room_size <= 0.0 || decay.is_nan() || decay <= 0.0 {
+			return Err(api_error(&format!("Room size and decay have to be positive, got {} and {} s", room_size, decay)));
+		}
+		if !(0.0..=1.0).contains(&mix) {
+			return Err(api_error("Mix has to be between 0 and 1"));
+		}
+		return Ok(self.get_processed_signal(|samples| effects::reverb(samples, self.sampling_frequency, room_size, decay, mix)));
+	}
+
+	/// Adds echoes repeating every `delay_seconds`, each `feedback` times quieter than the previous one
+	/// `mix` is between 0 (dry) and 1 (wet)
+	pub fn apply_echo(&self, delay_seconds: f64, feedback: f64, mix: f64) -> Vec<CoordPair> {
+		return self.get_processed_signal(|samples| effects::echo(samples, self.sampling_frequency, delay_seconds, feedback, mix));
+	}
+
+	/// Flanger - short delay swept by an LFO of `rate` Hz over `depth_ms` milliseconds, with `feedback`
+	/// `mix` is between 0 (dry) and 1 (wet)
+	pub fn apply_flanger(&self, rate: f64, depth_ms: f64, feedback: f64, mix: f64) -> Vec<CoordPair> {
+		return self.get_processed_signal(|samples| effects::modulated_delay(samples, self.sampling_frequency, 1.0, depth_ms, rate, feedback, mix));
+	}
+
+	/// Chorus - longer delay swept by an LFO of `rate` Hz over `depth_ms` milliseconds, without feedback
+	/// `mix` is between 0 (dry) and 1 (wet)
+	pub fn apply_chorus(&self, rate: f64, depth_ms: f64, mix: f64) -> Vec<CoordPair> {
+		return self.get_processed_signal(|samples| effects::modulated_delay(samples, self.sampling_frequency, 20.0, depth_ms, rate, 0.0, mix));
+	}
+
+	/// Reduces resolution of the signal to `bits` bits and its rate by holding every `downsample_factor`th sample
+	pub fn apply_bitcrush(&self, bits: u32, downsample_factor: usize) -> Vec<CoordPair> {
+		return self.get_processed_signal(|samples| effects::bitcrush(samples, bits, downsample_factor));
+	}
+
+	/// Multiplies the signal by a sine carrier of `carrier_freq` Hz
+	pub fn apply_ring_mod(&self, carrier_freq: f64) -> Vec<CoordPair> {
+		return effects::ring_modulation(self.compose_signal(), carrier_freq);
+	}
+
+	/// Amplitude spectrum of the signal, frequency in Hz and amplitude pairs from DC to Nyquist frequency
+	/// The signal is zero-padded to the next power of two, which interpolates the spectrum without adding information
+	pub fn get_spectrum(&self) -> Vec<CoordPair> {
+		let spectrum = transforms::spectrum(&self.get_signal_values(), self.sampling_frequency);
+		return spectrum.frequencies().into_iter().zip(spectrum.magnitudes()).map(|(x, y)| CoordPair { x, y }).collect();
+	}
+
+	/// Amplitude spectrum in dB relative to a sine of amplitude 1 versus frequency in Hz
+	pub fn get_amplitude_spectrum_db(&self) -> Vec<CoordPair> {
+		let spectrum = transforms::spectrum(&self.get_signal_values(), self.sampling_frequency);
+		return spectrum.frequencies().into_iter().zip(spectrum.magnitudes_db()).map(|(x, y)| CoordPair { x, y }).collect();
+	}
+
+	/// Unwrapped phase spectrum in radians versus frequency in Hz
+	pub fn get_phase_spectrum(&self) -> Vec<CoordPair> {
+		let spectrum = transforms::spectrum(&self.get_signal_values(), self.sampling_frequency);
+		return spectrum.frequencies().into_iter().zip(spectrum.unwrapped_phases()).map(|(x, y)| CoordPair { x, y }).collect();
+	}
+
+	/// Magnitudes of spectra of `window_size` samples long frames taking turns every `hop_size` samples
+	/// Frames are weighted with window `window_type`, one of the windows of `apply_window`
+	pub fn get_spectrogram(&self, window_size: usize, hop_size: usize, window_type: &str) -> Result<transforms::Spectrogram, ApiError> {
+		let window = windows::create(window_type, window_size.max(1)).ok_or_else(|| api_error(&format!("Unknown window {}", window_type)))?;
+		return Ok(transforms::spectrogram(&self.get_signal_values(), self.starting_time, self.sampling_frequency, &window, hop_size.max(1)));
+	}
+
+	/// Spectrum of the signal multiplied by window `window_type` of the same length, one of the windows of `apply_window`
+	/// Amplitudes are corrected for the window, so a sine still reads its amplitude
+	pub fn get_windowed_spectrum(&self, window_type: &str) -> Result<transforms::Spectrum, ApiError> {
+		let values = self.get_signal_values();
+		let window = windows::create(window_type, values.len()).ok_or_else(|| api_error(&format!("Unknown window {}", window_type)))?;
+		return Ok(transforms::windowed_spectrum(&values, self.sampling_frequency, &window));
+	}
+
+	/// Signal `id` over its duration multiplied by window `window_type`:
+	/// "rectangular", "hann", "hamming", "blackman", "bartlett" or "kaiser" (β = 8.6)
+	pub fn apply_window(&self, signal_id: u32, window_type: &str) -> Result<Vec<CoordPair>, ApiError> {
+		let signal_index = self.index_of(signal_id)?;
+		let points = self.get_points_in_window(signal_index);
+		let window = windows::create(window_type, points.len()).ok_or_else(|| api_error(&format!("Unknown window {}", window_type)))?;
+		return Ok(self.calculate_signal_at(signal_index, &points).into_iter().zip(window).map(|(point, weight)| {
+			return CoordPair {
+				x: point.x,
+				y: point.y * weight,
+			};
+		}).collect());
+	}
+
+	/// Same spectrum as `get_spectrum` with phase of every bin
+	pub fn get_spectrum_with_phase(&self) -> transforms::Spectrum {
+		return transforms::spectrum(&self.get_signal_values(), self.sampling_frequency);
+	}
+
+	/// `get_spectrum_with_phase` calling `callback` with the progress of the FFT, see `ProgressCallback`
+	pub fn get_spectrum_with_progress(&self, callback: Option<progress::ProgressCallback>) -> Result<transforms::Spectrum, ApiError> {
+		let values = self.get_signal_values();
+		return transforms::windowed_spectrum_reporting(&values, self.sampling_frequency, &vec![1.0; values.len()], &mut progress::reporter(callback)).map_err(|error| api_error(&error));
+	}
+
+	/// Adds signal of `length` samples starting at `starting_time` synthesized from a spectrum in the form of `get_spectrum_with_phase`,
+	/// given by real and imaginary parts of its bins. Returns id of the new signal
+	/// Spectrum of the signal itself with `length` equal to the number of its samples gives back the same signal
+	pub fn add_inverse_fft(&mut self, real: Vec<f64>, imag: Vec<f64>, length: usize) -> Result<u32, ApiError> {
+		let values: Vec<complex::Complex> = real.into_iter().zip(imag).map(|(re, im)| complex::Complex::new(re, im)).collect();
+		return self.add_from_spectrum(&values, length);
+	}
+
+	/// Same as `add_inverse_fft`, but bins are given by magnitudes and phases in radians
+	pub fn add_inverse_fft_polar(&mut self, magnitudes: Vec<f64>, phases: Vec<f64>, length: usize) -> Result<u32, ApiError> {
+		let values: Vec<complex::Complex> = magnitudes.into_iter().zip(phases).map(|(magnitude, phase)| complex::Complex::from_polar(magnitude, phase)).collect();
+		return self.add_from_spectrum(&values, length);
+	}
+
+	/// Signal with its least-squares polynomial trend of given `order` removed
+	/// Order 0 removes the mean, order 1 a linear drift
+	pub fn detrend(&self, order: usize) -> Vec<CoordPair> {
+		let mut signal = self.compose_signal();
+		let times: Vec<f64> = signal.iter().map(|point| point.x).collect();
+		let values: Vec<f64> = signal.iter().map(|point| point.y).collect();
+		for (point, value) in signal.iter_mut().zip(analysis::detrend(&times, &values, order)) {
+			point.y = value;
+		}
+		return signal;
+	}
+
+	/// Frequency and amplitude of the strongest spectral peak of the signal, more precise than the FFT bin spacing
+	pub fn estimate_peak_frequency(&self) -> Option<transforms::PeakEstimate> {
+		return transforms::estimate_peak(&self.get_signal_values(), self.sampling_frequency);
+	}
+
+	/// Adds noise of `noise_kind` ("uniform" or "normal") over the whole signal with power giving `target_snr_db` dB of SNR
+	/// Returns the id of the added noise signal
+	pub fn add_noise_for_snr(&mut self, target_snr_db: f64, noise_kind: &str) -> Result<u32, ApiError> {
+		let signal = self.compose_signal();
+		let (Some(first), Some(last)) = (signal.first(), signal.last()) else {
+			return Err(api_error("The signal has no samples"));
+		};
+		// noise covers the same samples as the signal, whose start isn't always the starting time
+		let start_offset = first.x - self.starting_time;
+		let duration = last.x - first.x + self.sampling_frequency.recip();
+		let signal_power = signal.iter().map(|point| point.y * point.y).sum::<f64>() / signal.len() as f64;
+		let noise_power = signal_power / 10.0_f64.powf(target_snr_db / 10.0);
+		let (type_name, amplitude) = match noise_kind {
+			// uniform noise between -A and A has power A^2 / 3
+			"uniform" => ("uniform_noise", (3.0 * noise_power).sqrt()),
+			// normal noise amplitude is its standard deviation
+			"normal" => ("normal_noise", noise_power.sqrt()),
+			_ => return Err(api_error(&format!("Unknown noise kind {}", noise_kind))),
+		};
+		return self.push_signal(type_name, "", &[("duration", duration), ("start_offset", start_offset), ("amplitude", amplitude)]);
+	}
+
+	/// Simulates loss of samples - every received sample starts a run of `burst_length` lost samples with `loss_probability`
+	/// Lost samples are handled with `concealment`: "zero", "remove", "hold" (repeat last received) or "linear" (interpolate)
+	/// With a seed set the same samples are lost every time
+	pub fn simulate_dropouts(&self, loss_probability: f64, burst_length: usize, concealment: &str) -> Result<Vec<CoordPair>, ApiError> {
+		if !effects::is_concealment(concealment) {
+			return Err(api_error(&format!("Unknown concealment {}", concealment)));
+		}
+		if !(0.0..=1.0).contains(&loss_probability) {
+			return Err(api_error("Loss probability has to be between 0 and 1"));
+		}
+		let signal = self.compose_signal();
+		let lost = effects::dropout_mask(signal.len(), loss_probability, burst_length, &mut self.get_rng(u64::MAX - 2));
+		return Ok(effects::conceal_dropouts(signal, &lost, concealment));
+	}
+
+	/// Recognizes DTMF key presses in the signal, returning keys with times of their tones
+	pub fn decode_dtmf(&self) -> Vec<dtmf::DtmfDigit> {
+		return dtmf::decode(&self.get_signal_values(), self.starting_time, self.sampling_frequency);
+	}
+
+	/// Dominant frequency of the signal measured by counting zero crossings and by finding the spectral peak
+	pub fn measure_frequency(&self) -> analysis::FrequencyMeasurement {
+		let signal = self.compose_signal();
+		let times: Vec<f64> = signal.iter().map(|point| point.x).collect();
+		let values: Vec<f64> = signal.iter().map(|point| point.y).collect();
+		return analysis::FrequencyMeasurement {
+			zero_crossing: analysis::zero_crossing_frequency(&times, &values),
+			fft_peak: transforms::estimate_peak(&values, self.sampling_frequency).map(|peak| peak.frequency).unwrap_or(f64::NAN),
+		};
+	}
+
+	/// Parts of the signal with short-time level of at least `threshold_db` dBFS lasting at least `min_duration` s
+	pub fn segment_by_energy(&self, threshold_db: f64, min_duration: f64) -> Vec<analysis::TimeInterval> {
+		let signal = self.compose_signal();
+		let times: Vec<f64> = signal.iter().map(|point| point.x).collect();
+		let values: Vec<f64> = signal.iter().map(|point| point.y).collect();
+		return analysis::segment_by_energy(&times, &values, self.sampling_frequency, threshold_db, min_duration);
+	}
+
+	/// Stores the signal converted by a comparator with hysteresis as a new signal, returning its id
+	/// Result is the peak amplitude of the signal after rising above `threshold_high`, and its negation after falling below `threshold_low`
+	pub fn comparator(&mut self, threshold_high: f64, threshold_low: f64) -> u32 {
+		let values = self.get_signal_values();
+		let amplitude = values.iter().fold(0.0, |peak: f64, value| peak.max(value.abs()));
+		let samples = effects::comparator(&values, threshold_high, threshold_low, amplitude);
+		return self.add_sampled(self.starting_time, samples);
+	}
+
+	/// Exponential sine sweep at the sampling frequency of the processor, to be played through a measured system
+	pub fn get_log_sweep(&self, start_freq: f64, end_freq: f64, duration: f64) -> Vec<f64> {
+		return measurement::log_sweep(start_freq, end_freq, duration, self.sampling_frequency);
+	}
+
+	/// Impulse response of a system which gave `system_output` when fed with `get_log_sweep` of the same parameters
+	/// Time 0 is the moment the sweep started playing
+	pub fn measure_impulse_response(&self, system_output: Vec<f64>, start_freq: f64, end_freq: f64, duration: f64) -> Vec<CoordPair> {
+		let response = measurement::impulse_response(&system_output, start_freq, end_freq, duration, self.sampling_frequency);
+		return response.into_iter().enumerate().map(|(index, y)| CoordPair { x: index as f64 / self.sampling_frequency, y }).collect();
+	}
+
+	/// RMS level in dBFS of every octave (1) or fractional octave (for example 3 for third-octave) band of the signal
+	pub fn band_levels(&self, bands_per_octave: u32) -> Vec<acoustics::BandLevel> {
+		return acoustics::band_levels(&self.get_signal_values(), self.sampling_frequency, bands_per_octave);
+	}
+
+	/// Signal filtered with frequency weighting `curve` - "A", "C" or "Z" (none)
+	pub fn apply_weighting(&self, curve: &str) -> Result<Vec<CoordPair>, ApiError> {
+		if !acoustics::is_weighting(curve) {
+			return Err(api_error(&format!("Unknown weighting curve {}", curve)));
+		}
+		return Ok(self.get_processed_signal(|samples| acoustics::apply_weighting(samples, self.sampling_frequency, curve)));
+	}
+
+	/// RMS level of the signal in dBFS weighted with `curve` - "A", "C" or "Z" (none), giving dB(A) or dB(C) readouts
+	pub fn weighted_level_db(&self, curve: &str) -> Result<f64, ApiError> {
+		if !acoustics::is_weighting(curve) {
+			return Err(api_error(&format!("Unknown weighting curve {}", curve)));
+		}
+		return Ok(acoustics::weighted_level_db(&self.get_signal_values(), self.sampling_frequency, curve));
+	}
+
+	/// Peak and RMS level in dBFS and integrated loudness in LUFS of the signal
+	pub fn level_meters(&self) -> acoustics::LevelMeters {
+		return acoustics::level_meters(&self.get_signal_values(), self.sampling_frequency);
+	}
+
+	/// Phase in radians by which signal `id_a` leads signal `id_b` at `freq` Hz, between -π and π
+	pub fn phase_difference(&self, id_a: u32, id_b: u32, freq: f64) -> Result<f64, ApiError> {
+		let index_a = self.index_of(id_a)?;
+		let index_b = self.index_of(id_b)?;
+		let (points, values_a, values_b) = self.get_aligned_pair(index_a, index_b);
+		return Ok(analysis::phase_difference(&points, &values_a, &values_b, freq));
+	}
+
+	/// Magnitude squared coherence between signals `id_a` and `id_b` versus frequency in Hz
+	/// Spectra are averaged over segments of `segment_length` samples, rounded up to a power of two
+	pub fn coherence(&self, id_a: u32, id_b: u32, segment_length: usize) -> Result<Vec<CoordPair>, ApiError> {
+		let index_a = self.index_of(id_a)?;
+		let index_b = self.index_of(id_b)?;
+		let (_, values_a, values_b) = self.get_aligned_pair(index_a, index_b);
+		return analysis::coherence(&values_a, &values_b, self.sampling_frequency, segment_length).map_err(|error| api_error(&format!("Could not calculate coherence: {}", error)));
+	}
+
+	/// Frequency response of the system which turned signal `input_id` into signal `output_id`
+	/// Spectra are averaged over segments of 1024 samples, which sets the frequency resolution
+	pub fn estimate_transfer_function(&self, input_id: u32, output_id: u32) -> Result<analysis::TransferFunctionEstimate, ApiError> {
+		let input_index = self.index_of(input_id)?;
+		let output_index = self.index_of(output_id)?;
+		let (_, input, output) = self.get_aligned_pair(input_index, output_index);
+		return analysis::estimate_transfer_function(&input, &output, self.sampling_frequency, 1024).map_err(|error| api_error(&format!("Could not estimate transfer function: {}", error)));
+	}
+
+	/// Removes white noise from the signal by thresholding its wavelet coefficients over `levels` levels
+	/// `threshold_rule` is "universal" or "sure", `thresholding` is "hard" or "soft"
+	pub fn wavelet_denoise(&self, levels: usize, threshold_rule: &str, thresholding: &str) -> Result<wavelets::WaveletDenoising, ApiError> {
+		return wavelets::denoise(&self.get_signal_values(), levels, threshold_rule, thresholding).map_err(|error| api_error(&format!("Could not denoise: {}", error)));
+	}
+
+	/// Discrete wavelet transform of the signal over `levels` levels with `wavelet`, "haar" or "db4"
+	/// The signal is zero padded to a multiple of 2^`levels` samples and treated as periodic
+	pub fn get_dwt(&self, levels: usize, wavelet: &str) -> Result<wavelets::WaveletTransform, ApiError> {
+		return wavelets::transform(&self.get_signal_values(), levels, wavelet).map_err(|error| api_error(&format!("Could not transform: {}", error)));
+	}
+
+	/// Adds signal starting at `starting_time` reconstructed from wavelet `transform`. Returns id of the new signal
+	pub fn add_inverse_dwt(&mut self, transform: &wavelets::WaveletTransform) -> u32 {
+		return self.add_sampled(self.starting_time, transform.inverse());
+	}
+
+	/// Lowpass FIR of `taps` coefficients with `cutoff` in Hz, sinc response truncated by window `window_type`,
+	/// one of the windows of `apply_window`. Odd `taps` delay the signal by (`taps` - 1) / 2 samples
+	pub fn design_fir_lowpass(&self, cutoff: f64, taps: usize, window_type: &str) -> Result<filters::Filter, ApiError> {
+		let window = self.create_fir_window(taps, window_type)?;
+		return Ok(filters::Filter::new(filters::fir_lowpass(self.to_normalized_frequency(cutoff)?, &window), vec![1.0]));
+	}
+
+	/// Highpass counterpart of `design_fir_lowpass`, `taps` must be odd
+	pub fn design_fir_highpass(&self, cutoff: f64, taps: usize, window_type: &str) -> Result<filters::Filter, ApiError> {
+		let window = self.create_fir_window(taps, window_type)?;
+		let kernel = filters::fir_highpass(self.to_normalized_frequency(cutoff)?, &window).map_err(|error| api_error(&format!("Could not design highpass: {}", error)))?;
+		return Ok(filters::Filter::new(kernel, vec![1.0]));
+	}
+
+	/// Bandpass FIR passing from `low_cutoff` to `high_cutoff` in Hz, designed like `design_fir_lowpass`
+	pub fn design_fir_bandpass(&self, low_cutoff: f64, high_cutoff: f64, taps: usize, window_type: &str) -> Result<filters::Filter, ApiError> {
+		let window = self.create_fir_window(taps, window_type)?;
+		let kernel = filters::fir_bandpass(self.to_normalized_frequency(low_cutoff)?, self.to_normalized_frequency(high_cutoff)?, &window);
+		return Ok(filters::Filter::new(kernel, vec![1.0]));
+	}
+
+	/// Bandstop FIR rejecting from `low_cutoff` to `high_cutoff` in Hz, designed like `design_fir_lowpass`, `taps` must be odd
+	pub fn design_fir_bandstop(&self, low_cutoff: f64, high_cutoff: f64, taps: usize, window_type: &str) -> Result<filters::Filter, ApiError> {
+		let window = self.create_fir_window(taps, window_type)?;
+		let kernel = filters::fir_bandstop(self.to_normalized_frequency(low_cutoff)?, self.to_normalized_frequency(high_cutoff)?, &window)
+			.map_err(|error| api_error(&format!("Could not design bandstop: {}", error)))?;
+		return Ok(filters::Filter::new(kernel, vec![1.0]));
+	}
+
+	/// Adds signal `signal_id` passed through `filter` over its duration and returns id of the new signal
+	/// Filtering is causal and starts from rest, so the output keeps the length of the input and its tail is cut off
+	pub fn apply_filter(&mut self, signal_id: u32, filter: &filters::Filter) -> Result<u32, ApiError> {
+		let signal_index = self.index_of(signal_id)?;
+		return self.apply_filter_with(signal_index, |samples| Ok(filter.apply(samples)));
+	}
+
+	/// Second-order IIR section of kind `kind` with cutoff or center `frequency` in Hz and quality factor `quality`:
+	/// "lowpass", "highpass", "bandpass", "notch", "peaking", "lowshelf" or "highshelf"
+	/// `gain_db` is the boost or cut of peaking and shelving sections, ignored by the rest. Sections can be chained with `cascade`
+	pub fn design_biquad(&self, kind: &str, frequency: f64, quality: f64, gain_db: f64) -> Result<filters::BiquadFilter, ApiError> {
+		if quality <= 0.0 {
+			return Err(api_error(&format!("Quality factor must be positive, got {}", quality)));
+		}
+		return filters::biquad(kind, self.to_normalized_frequency(frequency)?, quality, gain_db).ok_or_else(|| api_error(&format!("Unknown biquad kind {}", kind)));
+	}
+
+	/// IIR of `order` designed from analog prototype `family`, "butterworth" or "chebyshev" with `ripple_db` of passband ripple,
+	/// `kind` is "lowpass" or "highpass" with edge `cutoff` in Hz. Realized as second-order sections, see `BiquadFilter`
+	pub fn design_iir(&self, family: &str, kind: &str, order: usize, cutoff: f64, ripple_db: f64) -> Result<filters::BiquadFilter, ApiError> {
+		return filters::analog_prototype_iir(family, kind, order, &[cutoff / self.sampling_frequency], ripple_db).map_err(|error| api_error(&format!("Could not design filter: {}", error)));
+	}
+
+	/// Band counterpart of `design_iir`, `kind` is "bandpass" or "bandstop" between `low_edge` and `high_edge` in Hz
+	/// The filter has twice as many poles as `order`
+	pub fn design_iir_band(&self, family: &str, kind: &str, order: usize, low_edge: f64, high_edge: f64, ripple_db: f64) -> Result<filters::BiquadFilter, ApiError> {
+		let edges = [low_edge / self.sampling_frequency, high_edge / self.sampling_frequency];
+		return filters::analog_prototype_iir(family, kind, order, &edges, ripple_db).map_err(|error| api_error(&format!("Could not design filter: {}", error)));
+	}
+
+	/// Lowest order of a `family` lowpass or highpass for `design_iir` that loses at most `ripple_db` at `passband_edge`
+	/// and at least `attenuation_db` at `stopband_edge`, edges in Hz. Stopband edge above the passband edge means a lowpass
+	pub fn estimate_iir_order(&self, family: &str, passband_edge: f64, stopband_edge: f64, ripple_db: f64, attenuation_db: f64) -> Result<usize, ApiError> {
+		let (passband, stopband) = (self.to_normalized_frequency(passband_edge)?, self.to_normalized_frequency(stopband_edge)?);
+		return filters::minimum_iir_order(family, passband, stopband, ripple_db, attenuation_db).map_err(|error| api_error(&format!("Could not estimate order: {}", error)));
+	}
+
+	/// Signal passed through the cascade of `biquad`
+	pub fn apply_biquad(&self, biquad: &filters::BiquadFilter) -> Vec<CoordPair> {
+		return self.get_processed_signal(|samples| biquad.apply(samples));
+	}
+
+	/// Adds signal `signal_id` passed through the cascade of `biquad` over its duration and returns id of the new signal
+	pub fn apply_biquad_to_signal(&mut self, signal_id: u32, biquad: &filters::BiquadFilter) -> Result<u32, ApiError> {
+		let signal_index = self.index_of(signal_id)?;
+		return self.apply_filter_with(signal_index, |samples| Ok(biquad.apply(samples)));
+	}
+
+	/// Response of `filter` at `n_points` frequencies evenly spread from 0 to the Nyquist frequency inclusive,
+	/// read as gain in dB with `magnitudes_db` and phase in radians with `unwrapped_phases`. Biquad cascades are analyzed through `to_filter`
+	pub fn get_frequency_response(&self, filter: &filters::Filter, n_points: usize) -> transforms::Spectrum {
+		let frequencies = self.get_response_frequencies(n_points);
+		let values = frequencies.iter().map(|frequency| filter.transfer_function_at(complex::Complex::from_polar(1.0, std::f64::consts::TAU * frequency / self.sampling_frequency))).collect();
+		return transforms::Spectrum::new(frequencies, values);
+	}
+
+	/// Group delay of `filter` in s versus frequency in Hz at the frequencies of `get_frequency_response`
+	pub fn get_group_delay(&self, filter: &filters::Filter, n_points: usize) -> Vec<CoordPair> {
+		return self.get_response_frequencies(n_points).into_iter().map(|frequency| {
+			return CoordPair {
+				x: frequency,
+				y: filter.group_delay_at(frequency / self.sampling_frequency) / self.sampling_frequency,
+			};
+		}).collect();
+	}
+
+	/// First `length` samples of the output of `filter` for a unit impulse at time 0, versus time in s
+	pub fn get_impulse_response(&self, filter: &filters::Filter, length: usize) -> Vec<CoordPair> {
+		let mut impulse = vec![0.0; length];
+		if let Some(first) = impulse.first_mut() {
+			*first = 1.0;
+		}
+		return self.get_filter_response(filter, &impulse);
+	}
+
+	/// First `length` samples of the output of `filter` for a unit step at time 0, versus time in s
+	pub fn get_step_response(&self, filter: &filters::Filter, length: usize) -> Vec<CoordPair> {
+		return self.get_filter_response(filter, &vec![1.0; length]);
+	}
+
+	/// Signal smoothed by a sliding window of `window_length` samples centered on each sample:
+	/// "moving_average", "weighted_moving_average" with triangular weights, or "median", which removes impulsive noise
+	pub fn apply_smoothing(&self, kind: &str, window_length: usize) -> Result<Vec<CoordPair>, ApiError> {
+		return self.try_get_processed_signal(|samples| filters::smooth(samples, kind, window_length).ok_or(format!("Unknown smoothing {}", kind)));
+	}
+
+	/// Adds signal `signal_id` smoothed like in `apply_smoothing` over its duration and returns id of the new signal
+	pub fn apply_smoothing_to_signal(&mut self, signal_id: u32, kind: &str, window_length: usize) -> Result<u32, ApiError> {
+		let signal_index = self.index_of(signal_id)?;
+		return self.apply_filter_with(signal_index, |samples| filters::smooth(samples, kind, window_length).ok_or(format!("Unknown smoothing {}", kind)));
+	}
+
+	/// Adds the full linear convolution of samples of signals `id_a` and `id_b` over their durations and returns its id
+	/// Result is as long as both inputs together less one sample and starts at the start of the first signal delayed by the offset of the second one
+	/// `fast` multiplies spectra instead of summing products directly, which is much quicker for long signals
+	pub fn convolve_signals(&mut self, id_a: u32, id_b: u32, fast: bool) -> Result<u32, ApiError> {
+		return self.convolve_signals_reporting(id_a, id_b, fast, &mut |_| true).map_err(|error| api_error(&format!("Could not convolve: {}", error)));
+	}
+
+	/// `convolve_signals` calling `callback` with its progress, see `ProgressCallback`. Cancelling adds no signal
+	pub fn convolve_signals_with_progress(&mut self, id_a: u32, id_b: u32, fast: bool, callback: Option<progress::ProgressCallback>) -> Result<u32, ApiError> {
+		return self.convolve_signals_reporting(id_a, id_b, fast, &mut progress::reporter(callback)).map_err(|error| api_error(&error));
+	}
+
+	/// Simulates ranging with the signal `probe_id` as the emitted pulse: its echo from a target `distance` m away
+	/// with waves travelling at `speed` m/s is received with uniform noise of `noise_amplitude`, and the distance is estimated
+	/// from the peak of the cross-correlation between the received signal and the probe
+	pub fn simulate_echo_distance(&self, probe_id: u32, distance: f64, speed: f64, noise_amplitude: f64) -> Result<ranging::EchoSimulation, ApiError> {
+		let probe_index = self.index_of(probe_id)?;
+		if distance < 0.0 || speed <= 0.0 {
+			return Err(api_error("Distance must not be negative and speed must be positive"));
+		}
+		let points = self.get_points_in_window(probe_index);
+		let Some(&start) = points.first() else {
+			return Err(api_error(&format!("Signal {} has no samples", probe_id)));
+		};
+		let probe = self.calculate_values_at(probe_index, &points);
+		return Ok(ranging::simulate_echo(&probe, start, self.sampling_frequency, distance, speed, noise_amplitude, &mut self.get_rng(u64::MAX - 1)));
+	}
+
+	/// Signal quantized to 2^`bits` levels spread evenly over its range, with the quantization error
+	/// `mode` is "rounding" to the nearest level or "truncation" to the level below
+	pub fn quantize(&self, bits: u32, mode: &str) -> Result<quantization::Quantization, ApiError> {
+		let (times, values): (Vec<f64>, Vec<f64>) = self.compose_signal().into_iter().map(|point| (point.x, point.y)).unzip();
+		return quantization::quantize(times, &values, bits, mode, "linear").map_err(|error| api_error(&format!("Could not quantize: {}", error)));
+	}
+
+	/// Same as `quantize`, but the signal is compressed with companding `law` before quantizing and expanded after,
+	/// which spends more levels on quiet parts. `law` is "mu_law" or "a_law", the error is still measured against the original signal
+	pub fn quantize_companded(&self, bits: u32, mode: &str, law: &str) -> Result<quantization::Quantization, ApiError> {
+		let (times, values): (Vec<f64>, Vec<f64>) = self.compose_signal().into_iter().map(|point| (point.x, point.y)).unzip();
+		return quantization::quantize(times, &values, bits, mode, law).map_err(|error| api_error(&format!("Could not quantize: {}", error)));
+	}
+
+	/// Signal compressed with companding `law`, "mu_law" or "a_law", with its peak taken as the full scale of the law
+	pub fn compand(&self, law: &str) -> Result<Vec<CoordPair>, ApiError> {
+		return self.get_companded_signal(law, false);
+	}
+
+	/// Signal expanded with companding `law`, the inverse of `compand`
+	pub fn expand(&self, law: &str) -> Result<Vec<CoordPair>, ApiError> {
+		return self.get_companded_signal(law, true);
+	}
+
+	/// Signal reconstructed from its samples at `output_frequency` Hz, normally much higher than the sampling frequency,
+	/// to be plotted over the original. `method` is "zero_order_hold", "first_order_hold" or "sinc",
+	/// which sums sinc pulses of `sinc_neighbours` samples on each side of every point
+	pub fn reconstruct_signal(&self, output_frequency: f64, method: &str, sinc_neighbours: usize) -> Result<Vec<CoordPair>, ApiError> {
+		let signal = self.compose_signal();
+		let start = signal.first().map_or(self.starting_time, |point| point.x);
+		let values: Vec<f64> = signal.into_iter().map(|point| point.y).collect();
+		let points = linspace_by_freq(start, start + values.len() as f64 / self.sampling_frequency, output_frequency);
+		let reconstructed = reconstruction::reconstruct(&values, start, self.sampling_frequency, &points, method, sinc_neighbours)
+			.map_err(|error| api_error(&format!("Could not reconstruct: {}", error)))?;
+		return Ok(points.into_iter().zip(reconstructed).map(|(x, y)| CoordPair { x, y }).collect());
+	}
+
+	/// Samples the signal at `new_fs` Hz and reconstructs it back at the sampling frequency with `method`, one of the methods
+	/// of `reconstruct_signal`, to show aliasing when `new_fs` is below twice the highest frequency of the signal
+	pub fn resample_and_reconstruct(&self, new_fs: f64, method: &str) -> Result<reconstruction::AliasingDemonstration, ApiError> {
+		if new_fs <= 0.0 {
+			return Err(api_error(&format!("Sampling frequency must be positive, got {}", new_fs)));
+		}
+		let original = self.compose_signal();
+		let start = original.first().map_or(self.starting_time, |point| point.x);
+		let sample_times = linspace_by_freq(start, start + original.len() as f64 / self.sampling_frequency, new_fs);
+		let samples = self.evaluate_combined(&sample_times);
+		let times: Vec<f64> = original.iter().map(|point| point.x).collect();
+		let reconstructed = reconstruction::reconstruct(&samples, start, new_fs, &times, method, reconstruction::DEFAULT_SINC_NEIGHBOURS)
+			.map_err(|error| api_error(&format!("Could not reconstruct: {}", error)))?;
+		return Ok(reconstruction::AliasingDemonstration::new(original, sample_times, samples, reconstructed));
+	}
+
+	/// Compares signal `processed_id` with signal `original_id`, taken at the same sampling points
+	/// according to the length policy. Identical signals have infinite SNR
+	pub fn compare_signals(&self, original_id: u32, processed_id: u32) -> Result<analysis::SignalComparison, ApiError> {
+		let original_index = self.index_of(original_id)?;
+		let processed_index = self.index_of(processed_id)?;
+		let (_, original, processed) = self.get_aligned_pair(original_index, processed_index);
+		return Ok(analysis::compare(&original, &processed));
+	}
+
+	/// Compares `samples` with the signal, sample by sample from its first sampling point
+	pub fn compare_signal_with(&self, samples: Vec<f64>) -> analysis::SignalComparison {
+		return analysis::compare(&self.get_signal_values(), &samples);
+	}
+
+	/// Mean, RMS, power, peak and other statistics of the whole signal
+	/// A signal made of a single periodic signal is measured over a whole number of its periods, which gives the same values as the infinite signal
+	pub fn get_statistics(&self) -> analysis::SignalStatistics {
+		return self.get_statistics_between(f64::NEG_INFINITY, f64::INFINITY);
+	}
+
+	/// Same as `get_statistics`, but only for the part of the signal from `start` to `end` in s
+	pub fn get_statistics_in(&self, start: f64, end: f64) -> analysis::SignalStatistics {
+		return self.get_statistics_between(start, end);
+	}
+
+	/// Histogram of values of the signal in `bin_count` bins evenly covering `range_min` to `range_max`
+	/// Equal bounds cover the range of the signal
+	pub fn get_histogram(&self, bin_count: usize, range_min: f64, range_max: f64) -> Result<analysis::Histogram, ApiError> {
+		if bin_count == 0 || range_min > range_max {
+			return Err(api_error("Histogram needs at least one bin and a range with its minimum below its maximum"));
+		}
+		let values = self.get_signal_values();
+		let (low, high) = if range_min == range_max {
+			values.iter().fold((f64::INFINITY, f64::NEG_INFINITY), |(low, high), value| (low.min(*value), high.max(*value)))
+		} else {
+			(range_min, range_max)
+		};
+		return Ok(analysis::histogram(&values, bin_count, low, high));
+	}
+
+	/// Total harmonic distortion of the signal with fundamental at `fundamental_freq` Hz over its first `harmonic_count` harmonics,
+	/// as a ratio of RMS values (multiply by 100 for percent)
+	pub fn measure_thd(&self, fundamental_freq: f64, harmonic_count: u32) -> f64 {
+		return analysis::total_harmonic_distortion(&self.get_signal_values(), self.sampling_frequency, fundamental_freq, harmonic_count);
+	}
+
+	/// Signal to noise and distortion ratio in dB, with the strongest spectral component taken as the signal
+	pub fn measure_sinad(&self) -> f64 {
+		return analysis::sinad(&self.get_signal_values(), self.sampling_frequency);
+	}
+
+	/// Adds the signal decimated by `factor` as a new signal sampled at the sampling frequency divided by `factor`, returning its id
+	/// Before keeping every `factor`th sample, an FIR lowpass of `filter_order` removes what would alias. Order 0 skips filtering
+	/// At the sampling frequency of the processor the new signal repeats its nearest sample
+	pub fn decimate(&mut self, factor: usize, filter_order: usize) -> Result<u32, ApiError> {
+		if factor == 0 {
+			return Err(api_error("Decimation factor must be at least 1"));
+		}
+		let signal = self.compose_signal();
+		let start = signal.first().map_or(self.starting_time, |point| point.x);
+		let values: Vec<f64> = signal.into_iter().map(|point| point.y).collect();
+		let decimated = resampling::decimate(&values, factor, filter_order);
+		return Ok(self.insert_sampled(self.sampling_frequency / factor as f64, start, decimated, "nearest"));
+	}
+
+	/// Adds the signal interpolated `factor` times as a new signal sampled at the sampling frequency multiplied by `factor`, returning its id
+	/// `method` is "hold", "linear" or "sinc" for a windowed sinc lowpass removing the spectral images left by inserting zeros
+	pub fn interpolate(&mut self, factor: usize, method: &str) -> Result<u32, ApiError> {
+		if factor == 0 {
+			return Err(api_error("Interpolation factor must be at least 1"));
+		}
+		let signal = self.compose_signal();
+		let start = signal.first().map_or(self.starting_time, |point| point.x);
+		let values: Vec<f64> = signal.into_iter().map(|point| point.y).collect();
+		let interpolated = resampling::interpolate(&values, factor, method).map_err(|error| api_error(&format!("Could not interpolate: {}", error)))?;
+		return Ok(self.insert_sampled(self.sampling_frequency * factor as f64, start, interpolated, "nearest"));
+	}
+
+	/// Adds the signal resampled to `target_fs` Hz, any ratio to the sampling frequency, as a new signal sampled at that rate
+	/// and returns its id. Lowering the rate removes content above the new Nyquist frequency first
+	pub fn resample(&mut self, target_fs: f64) -> Result<u32, ApiError> {
+		return self.resample_reporting(target_fs, &mut |_| true).map_err(|error| api_error(&format!("Could not resample: {}", error)));
+	}
+
+	/// `resample` calling `callback` with its progress, see `ProgressCallback`. Cancelling adds no signal
+	pub fn resample_with_progress(&mut self, target_fs: f64, callback: Option<progress::ProgressCallback>) -> Result<u32, ApiError> {
+		return self.resample_reporting(target_fs, &mut progress::reporter(callback)).map_err(|error| api_error(&error));
+	}
+
+	/// Signal resampled to `target_sample_rate` Hz as samples ready to copy into a Web Audio AudioBuffer
+	/// Signals peaking above 1 are scaled down to fit in -1..1, quieter ones keep their level. Infinite values become silence
+	pub fn get_audio_buffer(&self, target_sample_rate: f64) -> Result<Vec<f32>, ApiError> {
+		if !(target_sample_rate > 0.0 && target_sample_rate.is_finite()) {
+			return Err(api_error(&format!("Sample rate must be positive, got {}", target_sample_rate)));
+		}
+		let values = self.get_signal_values();
+		let resampled = if target_sample_rate == self.sampling_frequency { values } else { resampling::resample_bandlimited(&values, self.sampling_frequency, target_sample_rate) };
+		let peak = resampled.iter().filter(|value| value.is_finite()).fold(0.0, |peak: f64, value| peak.max(value.abs()));
+		let scale = if peak > 1.0 { peak.recip() } else { 1.0 };
+		return Ok(resampled.into_iter().map(|value| if value.is_finite() { (value * scale).clamp(-1.0, 1.0) as f32 } else { 0.0 }).collect());
+	}
+
+	/// Fundamental period of the signal with its first cycle and the average of all cycles, None for signals that aren't periodic
+	pub fn extract_period(&self) -> Option<analysis::PeriodExtraction> {
+		return analysis::extract_period(&self.get_signal_values(), self.sampling_frequency);
+	}
+
+	/// Fits uniform and normal distributions to the values of the signal
+	pub fn fit_distribution(&self) -> analysis::DistributionFit {
+		return analysis::fit_distribution(&self.get_signal_values());
+	}
+}
+
 impl SignalProcessor {
-	pub fn new(sampling_frequency: f64, starting_time: f64) -> Self {
-		return Self {
+	/// `load_project` for Rust callers, with the error as text whether or not the wasm layer is built
+	pub fn from_project(json: &str) -> Result<SignalProcessor, String> {
+		return project::load(json).map_err(|error| format!("Could not load project: {}", error));
+	}
+
+	/// `export_wav` for Rust callers, with the error as text whether or not the wasm layer is built
+	pub fn encode_wav(&self, bit_depth: u16) -> Result<Vec<u8>, String> {
+		return wav::encode(&self.get_signal_values(), self.sampling_frequency, bit_depth).map_err(|error| format!("Could not export WAV: {}", error));
+	}
+
+	/// `new` with the error as text, shared with loading projects
+	pub(crate) fn with_sampling(sampling_frequency: f64, starting_time: f64) -> Result<Self, String> {
+		signals::validate_parameter("sampling_frequency", sampling_frequency)?;
+		if !starting_time.is_finite() {
+			return Err(format!("Starting time has to be a finite number, got {}", starting_time));
+		}
+		return Ok(Self {
 			sampling_frequency,
 			starting_time,
 			signals: Vec::new(),
+			signal_ids: Vec::new(),
+			next_signal_id: 0,
+			disabled_ids: Vec::new(),
+			metadata: Vec::new(),
+			modulations: Vec::new(),
+			jitter_rms: 0.0,
+			length_policy: mixing::LengthPolicy::ZeroPad,
+			combine_mode: mixing::CombineMode::Sum,
+			seed: None,
+			oversampling: 1,
+			stream_chunk: 0,
+			stream_position: 0,
+			stream_whole: Vec::new(),
+			cache: Mutex::new(Vec::new()),
+			single_precision: false,
+		});
+	}
+
+	/// Combination of all signals, `get_signal` without the check for an empty signal list
+	fn compose_signal(&self) -> Vec<CoordPair> {
+		return self.compose_part(self.get_output_index_range(), &mut Vec::new());
+	}
+
+	/// Values of the next chunk of the stream computed in `T`
+	fn next_chunk_values<T: Sample>(&mut self) -> Result<Vec<T>, ApiError> {
+		if self.stream_chunk == 0 {
+			return Err(api_error("Stream has to be started with begin_stream"));
+		}
+		let range = self.get_output_index_range();
+		let first = (range.start + self.stream_position).min(range.end);
+		let last = (first + self.stream_chunk).min(range.end);
+		let mut whole = std::mem::take(&mut self.stream_whole);
+		let values = self.compose_values(first..last, &mut whole);
+		self.stream_whole = whole;
+		self.stream_position += last - first;
+		return Ok(values);
+	}
+
+	/// Sampling points of the combined signal, the ones left by the length policy
+	fn get_output_points(&self) -> Vec<f64> {
+		return self.get_output_index_range().map(|index| self.get_sampling_point(index)).collect();
+	}
+
+	/// Positions among `get_sampling_points` of the points left by the length policy, without listing the points
+	fn get_output_index_range(&self) -> std::ops::Range<usize> {
+		let ending_point = self.starting_time + self.get_signal_end();
+		// same count as `linspace_by_freq`
+		let count = ((ending_point - self.starting_time) / self.sampling_frequency.recip()).floor() as usize;
+		if self.length_policy != mixing::LengthPolicy::Truncate {
+			return 0..count;
+		}
+		let (_, windows) = self.get_components();
+		let covered = mixing::covered_range(&windows, self.length_policy);
+		// first position whose point isn't before `time`, estimated and then corrected for rounding
+		let first_from = |time: f64| {
+			let mut index = ((time - self.starting_time) * self.sampling_frequency).ceil().clamp(0.0, count as f64) as usize;
+			while index > 0 && self.get_sampling_point(index - 1) >= time {
+				index -= 1;
+			}
+			while index < count && self.get_sampling_point(index) < time {
+				index += 1;
+			}
+			return index;
+		};
+		let first = first_from(covered.0);
+		return first..first_from(covered.1).max(first);
+	}
+
+	/// Sampling point at position `index` of `get_sampling_points`
+	fn get_sampling_point(&self, index: usize) -> f64 {
+		return self.starting_time + index as f64 * self.sampling_frequency.recip();
+	}
+
+	/// Positions among `get_output_index_range` of the points from `t_start` to `t_end` in s
+	fn get_output_index_range_between(&self, t_start: f64, t_end: f64) -> std::ops::Range<usize> {
+		let mut inside = self.get_output_index_range().filter(|index| {
+			let point = self.get_sampling_point(*index);
+			return point >= t_start && point < t_end;
+		});
+		let Some(first) = inside.next() else {
+			return 0..0;
+		};
+		return first..inside.last().unwrap_or(first) + 1;
+	}
+
+	/// Combined signal at positions `part` of `get_output_index_range`, computed in the precision of the processor
+	fn compose_part(&self, part: std::ops::Range<usize>, whole: &mut WholeValues) -> Vec<CoordPair> {
+		let values: Vec<f64> = if self.single_precision {
+			self.compose_values::<f32>(part.clone(), whole).into_iter().map(f64::from).collect()
+		} else {
+			self.compose_values(part.clone(), whole)
+		};
+		return part.zip(values).map(|(index, y)| CoordPair { x: self.get_sampling_point(index), y }).collect();
+	}
+
+	/// Combined values at positions `part` of `get_output_index_range`, with jitter and oversampling, the same as in the whole signal
+	/// Components that can't be computed at only some of the points are computed over the whole signal once and kept in `whole`
+	fn compose_values<T: Sample>(&self, part: std::ops::Range<usize>, whole: &mut WholeValues) -> Vec<T> {
+		let range = self.get_output_index_range();
+		// the oversampling filter reaches 8 samples to each side, computing them too makes parts join seamlessly
+		let margin = if self.oversampling > 1 { 8 } else { 0 };
+		let from = part.start.saturating_sub(margin).max(range.start);
+		let to = (part.end + margin).min(range.end).max(from);
+		let evaluation_points = self.get_evaluation_points(from..to);
+		let values: Vec<T> = if from == range.start && to == range.end {
+			self.evaluate_combined_within(&evaluation_points, None)
+		} else {
+			let offset = (from - range.start) * self.oversampling;
+			let positions: Vec<usize> = (offset..offset + evaluation_points.len()).collect();
+			self.add_whole_values(|| self.get_evaluation_points(range.clone()), whole);
+			self.evaluate_combined_within(&evaluation_points, Some((&positions, whole)))
+		};
+		if self.oversampling == 1 {
+			return values.into_iter().skip(part.start - from).take(part.len()).collect();
+		}
+		// removes content above the target Nyquist frequency before keeping every `oversampling`th value
+		let kernel: Vec<T> = filters::fir_lowpass(0.45 / self.oversampling as f64, &windows::blackman(16 * self.oversampling + 1)).into_iter().map(T::from_f64).collect();
+		return part.map(|index| filters::centered_fir_at(&values, &kernel, (index - from) * self.oversampling)).collect();
+	}
+
+	/// Points the signal is evaluated at for sampling points at positions `indices` of `get_sampling_points`
+	/// Every sampling point gets `oversampling` evenly spaced evaluation points starting at itself, moved by jitter
+	fn get_evaluation_points(&self, indices: std::ops::Range<usize>) -> Vec<f64> {
+		let step_time = (self.sampling_frequency * self.oversampling as f64).recip();
+		return indices.flat_map(|index| (0..self.oversampling).map(move |step| (index, step))).map(|(index, step)| {
+			let point = self.get_sampling_point(index) + step as f64 * step_time;
+			if self.jitter_rms == 0.0 {
+				return point;
+			}
+			// signal is taken at jittered instants, but reported on the nominal grid like a real converter would
+			// offsets are drawn for each position, so parts of the signal get the same ones as the whole
+			let offset: f64 = match self.seed {
+				Some(seed) => StdRng::seed_from_u64(signals::derive_seed(signals::derive_seed(seed, u64::MAX), (index * self.oversampling + step) as u64)).sample(rand_distr::StandardNormal),
+				None => rand::thread_rng().sample(rand_distr::StandardNormal),
+			};
+			return point + offset * self.jitter_rms;
+		}).collect();
+	}
+
+	/// Adds `signal` under a new id and returns the id
+	pub(crate) fn insert_signal(&mut self, signal: Box<dyn signals::CalculableSignal>) -> u32 {
+		let id = self.next_signal_id;
+		self.next_signal_id += 1;
+		self.signals.push(signal);
+		self.signal_ids.push(id);
+		return id;
+	}
+
+	/// Position of signal `id` in `signals`, None if there is no such signal
+	fn find_index(&self, id: u32) -> Option<usize> {
+		return self.signal_ids.iter().position(|signal_id| *signal_id == id);
+	}
+
+	/// Position of signal `id` in `signals`, fails if there is no such signal
+	fn index_of(&self, id: u32) -> Result<usize, ApiError> {
+		return self.find_index(id).ok_or_else(|| api_error(&format!("There is no signal with id {}", id)));
+	}
+
+	/// End of the latest signal in s relative to `starting_time`
+	fn get_signal_end(&self) -> f64 {
+		return self.signals.iter().map(|signal| signal.get_signal_end()).max_by(|x, y| x.partial_cmp(y).unwrap()).unwrap_or(0.0);
+	}
+
+	fn get_sampling_points(&self) -> Vec<f64> {
+		let ending_point = self.starting_time + self.get_signal_end(); // in seconds
+		return linspace_by_freq(self.starting_time, ending_point, self.sampling_frequency);
+	}
+
+	/// Indices and windows of all enabled signals except the ones serving only as a modulation source
+	fn get_components(&self) -> (Vec<usize>, Vec<(f64, f64)>) {
+		let components: Vec<usize> = (0..self.signals.len()).filter(|index| {
+			return !self.disabled_ids.contains(&self.signal_ids[*index]) && !self.modulations.iter().any(|modulation| matches!(modulation.source, modulation::ModulationSource::Oscillator { source_index, .. } if source_index == *index));
+		}).collect();
+		let windows = components.iter().map(|index| self.get_signal_window(*index)).collect();
+		return (components, windows);
+	}
+
+	/// Whether signal at `index` together with modulations targeting it gives values depending only on their points,
+	/// so it can be computed at some of the points alone or in separate pieces
+	fn evaluates_pointwise(&self, index: usize) -> bool {
+		return self.signals[index].is_pointwise() && self.modulations.iter().filter(|modulation| modulation.target_index == index).all(|modulation| {
+			return match &modulation.source {
+				// modulating frequency warps time cumulatively over all points
+				_ if modulation.parameter == "signal_freq" => false,
+				modulation::ModulationSource::Oscillator { source_index, .. } => self.signals[*source_index].is_pointwise(),
+				modulation::ModulationSource::Envelope(_) => true,
+			};
+		});
+	}
+
+	/// Combined value of all components at `points`, taken exactly there without jitter or oversampling
+	fn evaluate_combined(&self, points: &[f64]) -> Vec<f64> {
+		if self.single_precision {
+			return self.evaluate_combined_within::<f32>(points, None).into_iter().map(f64::from).collect();
+		}
+		return self.evaluate_combined_within(points, None);
+	}
+
+	/// Values over the whole `grid` of components that can't be computed at only some of its points, added to `whole`
+	/// unless they are there already. `grid` is only built when there is such a component
+	fn add_whole_values(&self, grid: impl FnOnce() -> Vec<f64>, whole: &mut WholeValues) {
+		let (components, windows) = self.get_components();
+		let missing: Vec<(usize, usize)> = components.into_iter().enumerate().filter(|(_, index)| {
+			return !self.evaluates_pointwise(*index) && !whole.iter().any(|(id, _)| *id == self.signal_ids[*index]);
+		}).collect();
+		if missing.is_empty() {
+			return;
+		}
+		let grid = grid();
+		for (position, index) in missing {
+			let values = if self.single_precision {
+				mixing::evaluate_aligned(&grid, &windows, position, self.length_policy, |points| self.calculate_values_cached::<f32>(index, points)).into_iter().map(f64::from).collect()
+			} else {
+				mixing::evaluate_aligned(&grid, &windows, position, self.length_policy, |points| self.calculate_values_cached(index, points))
+			};
+			whole.push((self.signal_ids[index], values));
+		}
+	}
+
+	/// `evaluate_combined` at `points` which are the ones at `positions` of a whole signal, taking values of components
+	/// in `whole`, from `add_whole_values`, at those positions instead of computing them
+	fn evaluate_combined_within<T: Sample>(&self, points: &[f64], within: Option<(&[usize], &WholeValues)>) -> Vec<T> {
+		let (components, windows) = self.get_components();
+		let evaluate = |(position, index): &(usize, usize)| {
+			let whole_values = within.and_then(|(positions, whole)| whole.iter().find(|(id, _)| *id == self.signal_ids[*index]).map(|(_, values)| (positions, values)));
+			if let Some((positions, values)) = whole_values {
+				return positions.iter().map(|position| T::from_f64(values[*position])).collect();
+			}
+			return mixing::evaluate_aligned(points, &windows, *position, self.length_policy, |points| self.calculate_values_cached(*index, points));
+		};
+		let components: Vec<(usize, usize)> = components.into_iter().enumerate().collect();
+		// signals are independent of each other, so each one gets its own thread
+		#[cfg(all(feature = "threads", not(target_arch = "wasm32")))]
+		let values = threads::map_parallel(&components, evaluate);
+		#[cfg(not(all(feature = "threads", not(target_arch = "wasm32"))))]
+		let values = components.iter().map(evaluate);
+		return values.into_iter().reduce(|combined, component| {
+			return combined.into_iter().zip(component).map(|(a, b)| self.combine_mode.combine(a, b)).collect();
+		}).unwrap_or_else(|| vec![T::default(); points.len()]);
+	}
+
+	/// Evaluates signal at `index` with all modulations and automations targeting it, 0 outside of its duration
+	fn calculate_signal_at(&self, index: usize, sampling_points: &[f64]) -> Vec<CoordPair> {
+		let times = self.get_signal_times(sampling_points);
+		let seeded = self.get_seeded_signal(index);
+		let signal = seeded.as_deref().unwrap_or(self.signals[index].as_ref());
+		let mut parameter_values: Vec<(String, Vec<f64>)> = Vec::new();
+		let targeting: Vec<&modulation::Modulation> = self.modulations.iter().filter(|modulation| modulation.target_index == index).collect();
+		// envelopes replace the set value, so they go before oscillators which add to it
+		let envelopes = targeting.iter().filter(|modulation| matches!(modulation.source, modulation::ModulationSource::Envelope(_)));
+		let oscillators = targeting.iter().filter(|modulation| matches!(modulation.source, modulation::ModulationSource::Oscillator { .. }));
+		for modulation in envelopes.chain(oscillators) {
+			let position = match parameter_values.iter().position(|(name, _)| *name == modulation.parameter) {
+				Some(position) => position,
+				None => {
+					let base_value = modulation::get_parameter(signal, &modulation.parameter).unwrap();
+					parameter_values.push((modulation.parameter.clone(), vec![base_value; sampling_points.len()]));
+					parameter_values.len() - 1
+				}
+			};
+			let values = &mut parameter_values[position].1;
+			match &modulation.source {
+				modulation::ModulationSource::Oscillator { source_index, depth } => {
+					let seeded_source = self.get_seeded_signal(*source_index);
+					let source = seeded_source.as_deref().unwrap_or(self.signals[*source_index].as_ref()).calculate_signal(&times);
+					for (value, source_point) in values.iter_mut().zip(source) {
+						*value += depth * source_point.y;
+					}
+				}
+				modulation::ModulationSource::Envelope(breakpoints) => {
+					for (value, time) in values.iter_mut().zip(&times) {
+						*value = modulation::evaluate_envelope(breakpoints, *time);
+					}
+				}
+			}
+		}
+		let values = if parameter_values.is_empty() {
+			signal.calculate_signal(&times)
+		} else {
+			modulation::calculate_modulated_signal(signal, &times, &parameter_values)
+		};
+		// signals only exist between their start and end, whatever their formula gives elsewhere
+		let (start, end) = self.get_signal_window(index);
+		return sampling_points.iter().zip(values).map(|(point, value)| {
+			return CoordPair {
+				x: *point,
+				y: if *point < start || *point >= end { 0.0 } else { value.y },
+			};
+		}).collect();
+	}
+
+	/// Copy of signal at `index` drawing its random values from the seed, None when there is no seed or the signal isn't random
+	fn get_seeded_signal(&self, index: usize) -> Option<Box<dyn signals::CalculableSignal>> {
+		return self.seed.and_then(|seed| self.signals[index].with_seed(signals::derive_seed(seed, self.signal_ids[index] as u64)));
+	}
+
+	/// `calculate_values_in` remembering the result, so a signal is only evaluated again when something it depends on changed
+	fn calculate_values_cached<T: Sample>(&self, index: usize, sampling_points: &[f64]) -> Vec<T> {
+		let Some(key) = self.get_cache_key(index, sampling_points) else {
+			return self.calculate_values_split(index, sampling_points);
+		};
+		let id = self.signal_ids[index];
+		if let Some((_, _, values)) = self.cache.lock().unwrap().iter().find(|(cached_id, cached_key, _)| *cached_id == id && *cached_key == key) {
+			return T::from_samples(values);
+		}
+		let values: Vec<T> = self.calculate_values_split(index, sampling_points);
+		let mut cache = self.cache.lock().unwrap();
+		cache.retain(|(cached_id, _, _)| *cached_id != id);
+		cache.push((id, key, T::into_samples(values.clone())));
+		return values;
+	}
+
+	/// `calculate_values_in` spread over threads in pieces of `sampling_points` when the signal allows it
+	#[cfg(all(feature = "threads", not(target_arch = "wasm32")))]
+	fn calculate_values_split<T: Sample>(&self, index: usize, sampling_points: &[f64]) -> Vec<T> {
+		if !self.evaluates_pointwise(index) {
+			return self.calculate_values_in(index, sampling_points);
+		}
+		return threads::evaluate_split(sampling_points, |points| self.calculate_values_in(index, points));
+	}
+
+	#[cfg(not(all(feature = "threads", not(target_arch = "wasm32"))))]
+	fn calculate_values_split<T: Sample>(&self, index: usize, sampling_points: &[f64]) -> Vec<T> {
+		return self.calculate_values_in(index, sampling_points);
+	}
+
+	/// `calculate_values_at` computed in `T`. Modulated signals are computed in f64, their parameters change at every point
+	fn calculate_values_in<T: Sample>(&self, index: usize, sampling_points: &[f64]) -> Vec<T> {
+		if self.modulations.iter().any(|modulation| modulation.target_index == index) {
+			return self.calculate_values_at(index, sampling_points).into_iter().map(T::from_f64).collect();
+		}
+		let seeded = self.get_seeded_signal(index);
+		let signal = seeded.as_deref().unwrap_or(self.signals[index].as_ref());
+		let (start, end) = self.get_signal_window(index);
+		return sampling_points.iter().zip(T::calculate(signal, &self.get_signal_times(sampling_points))).map(|(point, value)| {
+			return if *point < start || *point >= end { T::default() } else { value };
+		}).collect();
+	}
+
+	/// Hash of everything values of signal at `index` at `sampling_points` depend on: the points, the signal, modulations targeting it
+	/// and the seed. None when the signal or one of its modulation sources draws new random values on every evaluation
+	fn get_cache_key(&self, index: usize, sampling_points: &[f64]) -> Option<u64> {
+		let targeting: Vec<&modulation::Modulation> = self.modulations.iter().filter(|modulation| modulation.target_index == index).collect();
+		let sources: Vec<usize> = targeting.iter().filter_map(|modulation| match modulation.source {
+			modulation::ModulationSource::Oscillator { source_index, .. } => Some(source_index),
+			modulation::ModulationSource::Envelope(_) => None,
+		}).collect();
+		if self.seed.is_none() && std::iter::once(index).chain(sources.iter().copied()).any(|signal| self.signals[signal].with_seed(0).is_some()) {
+			return None;
+		}
+		let mut hasher = DefaultHasher::new();
+		sampling_points.iter().for_each(|point| point.to_bits().hash(&mut hasher));
+		// seeds are derived from ids of signals
+		(self.seed, self.signal_ids[index]).hash(&mut hasher);
+		signals::fingerprint(self.signals[index].as_ref(), &mut hasher);
+		for modulation in targeting {
+			modulation.parameter.hash(&mut hasher);
+			match &modulation.source {
+				modulation::ModulationSource::Oscillator { source_index, depth } => {
+					(source_index, depth.to_bits()).hash(&mut hasher);
+					signals::fingerprint(self.signals[*source_index].as_ref(), &mut hasher);
+				}
+				modulation::ModulationSource::Envelope(breakpoints) => {
+					breakpoints.iter().for_each(|(time, value)| (time.to_bits(), value.to_bits()).hash(&mut hasher));
+				}
+			}
+		}
+		return Some(hasher.finish());
+	}
+
+	/// Random number generator of stream `stream` derived from the seed, or seeded from entropy when there is no seed
+	/// Streams below u64::MAX - 2 belong to signals with the same id
+	fn get_rng(&self, stream: u64) -> StdRng {
+		return match self.seed {
+			Some(seed) => StdRng::seed_from_u64(signals::derive_seed(seed, stream)),
+			None => StdRng::from_entropy(),
+		};
+	}
+
+	/// Start and end in s of signal at `index` on the time axis of the sampling points
+	fn get_signal_window(&self, index: usize) -> (f64, f64) {
+		return (self.starting_time + self.signals[index].get_signal_start(), self.starting_time + self.signals[index].get_signal_end());
+	}
+
+	/// `sampling_points` as times signals are evaluated at, which are relative to `starting_time` like their start offsets
+	fn get_signal_times(&self, sampling_points: &[f64]) -> Vec<f64> {
+		return sampling_points.iter().map(|point| point - self.starting_time).collect();
+	}
+
+	fn add_from_spectrum(&mut self, values: &[complex::Complex], length: usize) -> Result<u32, ApiError> {
+		let samples = transforms::inverse_spectrum(values, length).map_err(|error| api_error(&format!("Could not synthesize signal: {}", error)))?;
+		return Ok(self.add_sampled(self.starting_time, samples));
+	}
+
+	/// Registers `samples` taken at the sampling frequency from `start` s as a new signal and returns its id
+	fn add_sampled(&mut self, start: f64, samples: Vec<f64>) -> u32 {
+		return self.insert_sampled(self.sampling_frequency, start, samples, "nearest");
+	}
+
+	/// Registers `samples` taken at `sample_rate` from `start` s, a time among the sampling points, as a new signal and returns its id
+	fn insert_sampled(&mut self, sample_rate: f64, start: f64, samples: Vec<f64>, interpolation: &str) -> u32 {
+		// start offsets of signals are relative to `starting_time`
+		return self.insert_signal(Box::new(signals::SampledSignal::new(sample_rate, start - self.starting_time, samples, interpolation.to_string())));
+	}
+
+	/// Adds signal at `index` over its duration processed by `filter` as a new signal and returns its id
+	fn apply_filter_with(&mut self, index: usize, filter: impl Fn(&[f64]) -> Result<Vec<f64>, String>) -> Result<u32, ApiError> {
+		let points = self.get_points_in_window(index);
+		let Some(&start) = points.first() else {
+			return Err(api_error(&format!("Signal {} has no samples", self.signal_ids[index])));
 		};
+		let filtered = filter(&self.calculate_values_at(index, &points)).map_err(|error| api_error(&error))?;
+		return Ok(self.add_sampled(start, filtered));
 	}
 
-	pub fn add_sine(&mut self, signal_freq: f64, duration: f64, start_offset: f64, amplitude: f64, phase_shift: f64) {
-		self.signals.push(Box::new(signals::SineSignal::new(signal_freq, duration, start_offset, amplitude, phase_shift)));
+	/// Sampling points within the duration of signal at `index`
+	fn get_points_in_window(&self, index: usize) -> Vec<f64> {
+		let (start, end) = self.get_signal_window(index);
+		return self.get_sampling_points().into_iter().filter(|point| *point >= start && *point < end).collect();
 	}
 
-	pub fn add_half_wave_rectified_sine(&mut self, signal_freq: f64, duration: f64, start_offset: f64, amplitude: f64, phase_shift: f64) {
-		self.signals.push(Box::new(signals::HalfWaveRectifiedSineSignal::new(signal_freq, duration, start_offset, amplitude, phase_shift)));
+	/// `n_points` frequencies in Hz from 0 to the Nyquist frequency inclusive
+	fn get_response_frequencies(&self, n_points: usize) -> Vec<f64> {
+		let step = self.sampling_frequency / 2.0 / n_points.saturating_sub(1).max(1) as f64;
+		return (0..n_points).map(|index| index as f64 * step).collect();
 	}
 
-	pub fn add_full_wave_rectified_sine(&mut self, signal_freq: f64, duration: f64, start_offset: f64, amplitude: f64, phase_shift: f64) {
-		self.signals.push(Box::new(signals::FullWaveRectifiedSineSignal::new(signal_freq, duration, start_offset, amplitude, phase_shift)));
+	/// Output of `filter` for `input` starting at time 0
+	fn get_filter_response(&self, filter: &filters::Filter, input: &[f64]) -> Vec<CoordPair> {
+		return filter.apply(input).into_iter().enumerate().map(|(index, y)| {
+			return CoordPair {
+				x: index as f64 / self.sampling_frequency,
+				y,
+			};
+		}).collect();
 	}
 
-	pub fn add_uniform_noise(&mut self, duration: f64, start_offset: f64, amplitude: f64) {
-		self.signals.push(Box::new(signals::UniformNoise::new(duration, start_offset, amplitude)));
+	fn get_companded_signal(&self, law: &str, inverse: bool) -> Result<Vec<CoordPair>, ApiError> {
+		return self.try_get_processed_signal(|samples| {
+			let full_scale = samples.iter().fold(0.0, |peak: f64, value| peak.max(value.abs()));
+			return quantization::compand(samples, law, inverse, full_scale).map_err(|error| format!("Could not compand: {}", error));
+		});
 	}
 
-	pub fn add_normal_noise(&mut self, duration: f64, start_offset: f64, amplitude: f64) {
-		self.signals.push(Box::new(signals::NormalNoise::new(duration, start_offset, amplitude)));
+	fn get_statistics_between(&self, start: f64, mut end: f64) -> analysis::SignalStatistics {
+		let (components, windows) = self.get_components();
+		if let ([index], [window]) = (components.as_slice(), windows.as_slice()) {
+			let modulated = self.modulations.iter().any(|modulation| modulation.target_index == *index);
+			if let (Some(period), false) = (self.signals[*index].get_period(), modulated) {
+				// evaluated from where both the requested range and the signal begin, for as many whole periods as fit
+				let first = start.max(window.0);
+				let periods = ((end.min(window.1) - first) / period).floor();
+				if periods >= 1.0 {
+					end = first + periods * period;
+				}
+			}
+		}
+		let values: Vec<f64> = self.compose_signal().into_iter().filter(|point| point.x >= start && point.x < end).map(|point| point.y).collect();
+		return analysis::statistics(&values);
 	}
 
-	pub fn add_rectangular(&mut self, signal_freq: f64, duration: f64, start_offset: f64, amplitude: f64, duty_cycle: f64) {
-		self.signals.push(Box::new(signals::RectangularSignal::new(signal_freq, duration, start_offset, amplitude, duty_cycle)));
+	/// Adds signal of `type_name` with `parameters` through the same path as `add_signal`, returns its id
+	fn push_signal(&mut self, type_name: &str, text: &str, parameters: &[(&str, f64)]) -> Result<u32, ApiError> {
+		let signal = project::build_signal(type_name, Vec::new(), text.to_string(), Vec::new(), parameters).map_err(|error| api_error(&format!("Could not add signal: {}", error)))?;
+		return Ok(self.insert_signal(signal));
+	}
+
+	fn convolve_signals_reporting(&mut self, id_a: u32, id_b: u32, fast: bool, progress: &mut dyn FnMut(f64) -> bool) -> Result<u32, String> {
+		let index_a = self.find_index(id_a).ok_or(format!("There is no signal with id {}", id_a))?;
+		let index_b = self.find_index(id_b).ok_or(format!("There is no signal with id {}", id_b))?;
+		let points_a = self.get_points_in_window(index_a);
+		let points_b = self.get_points_in_window(index_b);
+		let (Some(&start_a), Some(&start_b)) = (points_a.first(), points_b.first()) else {
+			return Err("Convolved signals need samples".to_string());
+		};
+		let a = self.calculate_values_at(index_a, &points_a);
+		let b = self.calculate_values_at(index_b, &points_b);
+		let samples = if fast { transforms::fft_convolve_reporting(&a, &b, progress)? } else { filters::convolve_reporting(&a, &b, progress)? };
+		return Ok(self.add_sampled(start_a + start_b - self.starting_time, samples));
 	}
 
-	pub fn add_symmetric_rectangular(&mut self, signal_freq: f64, duration: f64, start_offset: f64, amplitude: f64, duty_cycle: f64) {
-		self.signals.push(Box::new(signals::SymmetricRectangularSignal::new(signal_freq, duration, start_offset, amplitude, duty_cycle)));
+	fn resample_reporting(&mut self, target_fs: f64, progress: &mut dyn FnMut(f64) -> bool) -> Result<u32, String> {
+		if target_fs <= 0.0 {
+			return Err(format!("Sampling frequency must be positive, got {}", target_fs));
+		}
+		let signal = self.compose_signal();
+		let start = signal.first().map_or(self.starting_time, |point| point.x);
+		let values: Vec<f64> = signal.into_iter().map(|point| point.y).collect();
+		let resampled = resampling::resample_bandlimited_reporting(&values, self.sampling_frequency, target_fs, progress)?;
+		return Ok(self.insert_sampled(target_fs, start, resampled, "nearest"));
 	}
 
-	pub fn add_triangular(&mut self, signal_freq: f64, duration: f64, start_offset: f64, amplitude: f64, duty_cycle: f64) {
-		self.signals.push(Box::new(signals::TriangularSignal::new(signal_freq, duration, start_offset, amplitude, duty_cycle)));
+	/// Window `window_type` of `taps` points for FIR design, odd lengths give symmetric kernels with an integer delay
+	fn create_fir_window(&self, taps: usize, window_type: &str) -> Result<Vec<f64>, ApiError> {
+		if taps == 0 {
+			return Err(api_error("FIR filter needs at least one tap"));
+		}
+		return windows::create(window_type, taps).ok_or_else(|| api_error(&format!("Unknown window {}", window_type)));
 	}
 
-	pub fn add_unit_jump(&mut self, flip_offset: f64, duration: f64, start_offset: f64, amplitude: f64) {
-		self.signals.push(Box::new(signals::UnitJump::new(flip_offset, duration, start_offset, amplitude)));
+	/// `frequency` in Hz as cycles per sample, which must lie between 0 and the Nyquist frequency
+	fn to_normalized_frequency(&self, frequency: f64) -> Result<f64, ApiError> {
+		if frequency <= 0.0 || frequency >= self.sampling_frequency / 2.0 {
+			return Err(api_error(&format!("Frequency {} Hz is outside of 0 to {} Hz", frequency, self.sampling_frequency / 2.0)));
+		}
+		return Ok(frequency / self.sampling_frequency);
 	}
 
-	pub fn add_unit_pulse(&mut self, time_offset: f64, duration: f64, start_offset: f64, amplitude: f64) {
-		self.signals.push(Box::new(signals::UnitPulse::new(time_offset, duration, start_offset, amplitude)));
+	fn add_composite(&mut self, index_a: usize, index_b: usize, operation: &str) -> u32 {
+		let composite = signals::CompositeSignal::new(operation.to_string(), self.signals[index_a].clone(), self.signals[index_b].clone());
+		return self.insert_signal(Box::new(composite));
 	}
 
-	pub fn add_unit_noise(&mut self, probability: f64, duration: f64, start_offset: f64, amplitude: f64) {
-		self.signals.push(Box::new(signals::UnitNoise::new(probability, duration, start_offset, amplitude)));
+	/// Sampling points and values of signals at `index_a` and `index_b`, combined according to the length policy
+	fn get_aligned_pair(&self, index_a: usize, index_b: usize) -> (Vec<f64>, Vec<f64>, Vec<f64>) {
+		return mixing::align_pair(
+			&self.get_sampling_points(),
+			self.get_signal_window(index_a),
+			self.get_signal_window(index_b),
+			self.length_policy,
+			|points| self.calculate_values_at(index_a, points),
+			|points| self.calculate_values_at(index_b, points),
+		);
 	}
 
-	pub fn get_signal(&self) -> Vec<CoordPair> {
-		let signal_duration = self.signals.iter().map(|signal| signal.get_signal_end()).max_by(|x, y| x.partial_cmp(y).unwrap()).unwrap();
-		let ending_point = self.starting_time + signal_duration; // in seconds
-		let sampling_points = linspace_by_freq(self.starting_time, ending_point, self.sampling_frequency);
-		return self.signals[0].calculate_signal(&sampling_points);
+	fn calculate_values_at(&self, index: usize, sampling_points: &[f64]) -> Vec<f64> {
+		return self.calculate_signal_at(index, sampling_points).into_iter().map(|point| point.y).collect();
+	}
+
+	fn get_signal_values(&self) -> Vec<f64> {
+		return self.compose_signal().into_iter().map(|point| point.y).collect();
+	}
+
+	/// Runs `process` over values of the signal, keeping the sampling points
+	fn get_processed_signal(&self, process: impl Fn(&[f64]) -> Vec<f64>) -> Vec<CoordPair> {
+		let mut signal = self.compose_signal();
+		let values: Vec<f64> = signal.iter().map(|point| point.y).collect();
+		for (point, value) in signal.iter_mut().zip(process(&values)) {
+			point.y = value;
+		}
+		return signal;
+	}
+
+	/// `get_processed_signal` with a `process` that can fail
+	fn try_get_processed_signal(&self, process: impl Fn(&[f64]) -> Result<Vec<f64>, String>) -> Result<Vec<CoordPair>, ApiError> {
+		let mut signal = self.compose_signal();
+		let values: Vec<f64> = signal.iter().map(|point| point.y).collect();
+		for (point, value) in signal.iter_mut().zip(process(&values).map_err(|error| api_error(&error))?) {
+			point.y = value;
+		}
+		return Ok(signal);
 	}
 }
 
 /// `starting_point` is inclusive seconds
 /// `end_point` is exclusive seconds
 /// `freq` in in Hz
-#[wasm_bindgen]
+#[cfg_attr(feature = "wasm", wasm_bindgen)]
 pub fn linspace_by_freq(starting_point: f64, end_point: f64, freq: f64) -> Vec<f64> {
 	let step = freq.recip();
 	let points = ((end_point - starting_point) / step).floor() as usize;
 	return (0..points).map(|offset| starting_point + (offset as f64 * step)).collect();
+}
+
+#[cfg(test)]
+mod tests {
+	use super::*;
+
+	/// Processor with every kind of signal that depends on more than its own sampling point
+	fn scene_with_whole_signals(oversampling: usize, jitter_rms: f64) -> SignalProcessor {
+		let mut processor = SignalProcessor::with_sampling(1000.0, 0.0).unwrap();
+		processor.set_seed(5);
+		processor.set_oversampling(oversampling);
+		processor.set_jitter(jitter_rms);
+		add(&mut processor, "unit_pulse", &[("time_offset", 0.1), ("duration", 1.0), ("start_offset", 0.0), ("amplitude", 1.0)]);
+		add(&mut processor, "impulse_train", &[("period", 0.013), ("duration", 1.0), ("start_offset", 0.0), ("amplitude", 1.0)]);
+		add(&mut processor, "pink_noise", &[("duration", 1.0), ("start_offset", 0.0), ("amplitude", 0.5)]);
+		add(&mut processor, "brownian_noise", &[("duration", 0.8), ("start_offset", 0.1), ("amplitude", 0.5)]);
+		let carrier = add(&mut processor, "sine", &[("signal_freq", 50.0), ("duration", 1.0), ("start_offset", 0.0), ("amplitude", 1.0), ("phase_shift", 0.0)]);
+		let lfo = add(&mut processor, "sine", &[("signal_freq", 3.0), ("duration", 1.0), ("start_offset", 0.0), ("amplitude", 20.0), ("phase_shift", 0.0)]);
+		processor.add_modulation(lfo, carrier, "signal_freq", 1.0).ok().unwrap();
+		return processor;
+	}
+
+	fn add(processor: &mut SignalProcessor, type_name: &str, parameters: &[(&str, f64)]) -> u32 {
+		return processor.insert_signal(project::build_signal(type_name, Vec::new(), String::new(), Vec::new(), parameters).unwrap());
+	}
+
+	fn values(signal: &[CoordPair]) -> Vec<f64> {
+		return signal.iter().map(|point| point.y).collect();
+	}
+
+	#[test]
+	fn chunks_join_into_the_whole_signal() {
+		for (oversampling, jitter_rms, single_precision) in [(1, 0.0, false), (4, 0.0, false), (1, 1e-5, false), (4, 1e-5, false), (4, 1e-5, true)] {
+			let mut processor = scene_with_whole_signals(oversampling, jitter_rms);
+			processor.set_single_precision(single_precision);
+			let whole = values(&processor.compose_signal());
+			processor.begin_stream(97).ok().unwrap();
+			let mut chunks = Vec::new();
+			loop {
+				let chunk = processor.next_chunk().ok().unwrap();
+				if chunk.is_empty() {
+					break;
+				}
+				chunks.extend(chunk);
+			}
+			assert_eq!(chunks, whole, "oversampling {}, jitter {}, single precision {}", oversampling, jitter_rms, single_precision);
+		}
+	}
+
+	#[test]
+	fn single_precision_computes_in_f32() {
+		let mut processor = SignalProcessor::with_sampling(44100.0, 0.0).unwrap();
+		add(&mut processor, "sine", &[("signal_freq", 440.0), ("duration", 1.0), ("start_offset", 0.0), ("amplitude", 0.7), ("phase_shift", 0.3)]);
+		add(&mut processor, "triangular", &[("signal_freq", 110.0), ("duration", 1.0), ("start_offset", 0.0), ("amplitude", 0.2), ("duty_cycle", 0.3)]);
+		let double = processor.get_signal_arrays().values();
+		processor.set_single_precision(true);
+		let arrays = processor.get_signal_arrays();
+		assert!(arrays.values_ptr().is_null() && !arrays.values_f32_ptr().is_null());
+		let points = processor.get_output_points();
+		let expected: Vec<f32> = processor.signals[0].calculate_values_f32(&points).into_iter().zip(processor.signals[1].calculate_values_f32(&points)).map(|(a, b)| a + b).collect();
+		let single = arrays.values_f32();
+		assert_eq!(single, expected);
+		assert!(single.iter().zip(&double).all(|(single, double)| (*single as f64 - double).abs() < 1e-5));
+		assert!(single.iter().zip(&double).any(|(single, double)| *single != *double as f32));
+		processor.begin_stream(1000).ok().unwrap();
+		assert_eq!(processor.next_chunk_f32().ok().unwrap(), single[..1000]);
+	}
+
+	#[test]
+	fn seeded_noise_is_reproducible() {
+		let build = || {
+			let mut processor = SignalProcessor::with_sampling(1000.0, 0.0).unwrap();
+			processor.set_seed(42);
+			let sine = add(&mut processor, "sine", &[("signal_freq", 5.0), ("duration", 1.0), ("start_offset", 0.0), ("amplitude", 1.0), ("phase_shift", 0.0)]);
+			let noise = add(&mut processor, "normal_noise", &[("duration", 1.0), ("start_offset", 0.0), ("amplitude", 1.0)]);
+			return (processor, sine, noise);
+		};
+		let (first, _, first_noise) = build();
+		let (mut second, sine, second_noise) = build();
+		assert_eq!(values(&first.compose_signal()), values(&second.compose_signal()));
+		let noise = values(&second.get_signal_by_id(second_noise).ok().unwrap());
+		// removing another signal mustn't change the random values of the remaining ones
+		second.remove_signal(sine).ok().unwrap();
+		assert_eq!(values(&second.get_signal_by_id(second_noise).ok().unwrap()), noise);
+		assert_eq!(values(&first.get_signal_by_id(first_noise).ok().unwrap()), noise);
+		let reloaded = project::load(&first.save_project()).unwrap();
+		assert_eq!(values(&reloaded.compose_signal()), values(&first.compose_signal()));
+	}
+
+	#[test]
+	fn range_matches_the_whole_signal() {
+		for oversampling in [1, 4] {
+			let processor = scene_with_whole_signals(oversampling, 1e-5);
+			let whole = processor.compose_signal();
+			let range = processor.get_signal_range(0.5, 0.6);
+			let expected: Vec<&CoordPair> = whole.iter().filter(|point| point.x >= 0.5 && point.x < 0.6).collect();
+			assert_eq!(range.iter().map(|point| (point.x, point.y)).collect::<Vec<_>>(), expected.iter().map(|point| (point.x, point.y)).collect::<Vec<_>>());
+		}
+	}
+
+	#[test]
+	fn decimated_range_keeps_values_of_the_whole_signal() {
+		let processor = scene_with_whole_signals(1, 0.0);
+		let whole = processor.compose_signal();
+		for point in processor.get_signal_range_decimated(0.0, 1.0, 50) {
+			let position = whole.iter().position(|whole_point| whole_point.x == point.x).unwrap();
+			assert_eq!(point.y, whole[position].y);
+		}
+	}
+	#[test]
+	fn signals_start_at_the_starting_time() {
+		let mut processor = SignalProcessor::with_sampling(100.0, 10.0).unwrap();
+		let sine = add(&mut processor, "sine", &[("signal_freq", 5.0), ("duration", 1.0), ("start_offset", 0.0), ("amplitude", 1.0), ("phase_shift", 0.0)]);
+		let signal = processor.compose_signal();
+		assert_eq!(signal.len(), 100);
+		for point in &signal {
+			assert!((point.y - (std::f64::consts::TAU * 5.0 * (point.x - 10.0)).sin()).abs() < 1e-9, "{} at {}", point.y, point.x);
+		}
+		// sampled signals are placed on the same axis
+		let derived = processor.derive_signal(sine, sine, "add").ok().unwrap();
+		let derived_signal = processor.get_signal_by_id(derived).ok().unwrap();
+		assert_eq!(derived_signal.len(), 100);
+		assert!(derived_signal.iter().zip(&signal).all(|(derived, point)| derived.x == point.x && (derived.y - 2.0 * point.y).abs() < 1e-9));
+	}
+	#[test]
+	fn seeded_dropouts_are_reproducible() {
+		let mut processor = SignalProcessor::with_sampling(1000.0, 0.0).unwrap();
+		add(&mut processor, "sine", &[("signal_freq", 5.0), ("duration", 1.0), ("start_offset", 0.0), ("amplitude", 1.0), ("phase_shift", 0.0)]);
+		processor.set_seed(7);
+		let first = values(&processor.simulate_dropouts(0.1, 3, "zero").ok().unwrap());
+		assert_eq!(values(&processor.simulate_dropouts(0.1, 3, "zero").ok().unwrap()), first);
+		assert_ne!(first, values(&processor.compose_signal()));
+	}
+	#[test]
+	fn noise_for_snr_covers_the_signal() {
+		let mut processor = SignalProcessor::with_sampling(1000.0, 2.0).unwrap();
+		add(&mut processor, "sine", &[("signal_freq", 5.0), ("duration", 0.5), ("start_offset", 0.3), ("amplitude", 1.0), ("phase_shift", 0.0)]);
+		processor.set_length_policy("truncate").ok().unwrap();
+		let signal = processor.compose_signal();
+		let noise = processor.add_noise_for_snr(20.0, "uniform").ok().unwrap();
+		let interval = processor.get_signal_interval(noise).ok().unwrap();
+		assert!((interval.start - signal[0].x).abs() < 1e-9 && (interval.end - signal[signal.len() - 1].x - 0.001).abs() < 1e-9, "{} to {}", interval.start, interval.end);
+		assert_eq!(processor.compose_signal().len(), signal.len());
+	}
 }
\ No newline at end of file
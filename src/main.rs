@@ -0,0 +1,59 @@
+#![allow(clippy::needless_return)]
+
+use std::process::ExitCode;
+use std::time::Instant;
+
+use signalum::SignalProcessor;
+
+const USAGE: &str = "Usage: signalum <scene.json> <output.wav|output.csv> [--bit-depth 8|16|24|32]
+Renders a scene saved with `save_project` to a WAV or CSV file, chosen by the extension of the output";
+
+/// Paths of the scene and the output file and the WAV bit depth
+struct Arguments {
+	scene_path: String,
+	output_path: String,
+	bit_depth: u16,
+}
+
+fn parse_arguments(mut arguments: impl Iterator<Item = String>) -> Result<Arguments, String> {
+	let mut paths: Vec<String> = Vec::new();
+	let mut bit_depth = 16;
+	while let Some(argument) = arguments.next() {
+		match argument.as_str() {
+			"--bit-depth" => {
+				let value = arguments.next().ok_or("Missing value of --bit-depth")?;
+				bit_depth = match value.parse() {
+					Ok(depth @ (8 | 16 | 24 | 32)) => depth,
+					_ => return Err(format!("Unsupported bit depth {}, use 8, 16, 24 or 32", value)),
+				};
+			}
+			"-h" | "--help" => return Err(USAGE.to_string()),
+			_ => paths.push(argument),
+		}
+	}
+	let [scene_path, output_path] = <[String; 2]>::try_from(paths).map_err(|_| USAGE.to_string())?;
+	return Ok(Arguments { scene_path, output_path, bit_depth });
+}
+
+fn run(arguments: Arguments) -> Result<(), String> {
+	let scene = std::fs::read_to_string(&arguments.scene_path).map_err(|error| format!("Could not read {}: {}", arguments.scene_path, error))?;
+	let processor = SignalProcessor::from_project(&scene)?;
+	let started = Instant::now();
+	let output = match arguments.output_path.rsplit_once('.').map(|(_, extension)| extension.to_ascii_lowercase()).as_deref() {
+		Some("wav") => processor.encode_wav(arguments.bit_depth)?,
+		Some("csv") => processor.export_csv().into_bytes(),
+		_ => return Err(format!("Unknown output format of {}, use .wav or .csv", arguments.output_path)),
+	};
+	// timing goes to stderr, so the tool can be used for benchmarks
+	eprintln!("Rendered {} in {:.3} s", arguments.output_path, started.elapsed().as_secs_f64());
+	return std::fs::write(&arguments.output_path, output).map_err(|error| format!("Could not write {}: {}", arguments.output_path, error));
+}
+
+fn main() -> ExitCode {
+	let result = parse_arguments(std::env::args().skip(1)).and_then(run);
+	if let Err(error) = result {
+		eprintln!("{}", error);
+		return ExitCode::FAILURE;
+	}
+	return ExitCode::SUCCESS;
+}
\ No newline at end of file
@@ -0,0 +1,28 @@
+use std::f64::consts::TAU;
+
+use crate::transforms;
+
+/// Exponential sine sweep from `start_freq` to `end_freq` Hz lasting `duration` s, as described by Farina
+pub fn log_sweep(start_freq: f64, end_freq: f64, duration: f64, sampling_frequency: f64) -> Vec<f64> {
+	let rate = duration / (end_freq / start_freq).ln();
+	let length = (duration * sampling_frequency).round() as usize;
+	return (0..length).map(|index| {
+		let time = index as f64 / sampling_frequency;
+		return (TAU * start_freq * rate * ((time / rate).exp() - 1.0)).sin();
+	}).collect();
+}
+
+/// Impulse response of a system that produced `system_output` when fed with `log_sweep` of the same parameters
+/// Output is convolved with the time reversed sweep, attenuated by 6 dB per octave to make its spectrum flat
+/// Result starts at the moment the sweep started playing
+pub fn impulse_response(system_output: &[f64], start_freq: f64, end_freq: f64, duration: f64, sampling_frequency: f64) -> Vec<f64> {
+	let sweep = log_sweep(start_freq, end_freq, duration, sampling_frequency);
+	let rate = duration / (end_freq / start_freq).ln();
+	// sweep spends more time at low frequencies, the decaying envelope evens that out
+	let inverse_filter: Vec<f64> = sweep.iter().rev().enumerate().map(|(index, sample)| sample * (-(index as f64 / sampling_frequency) / rate).exp()).collect();
+	// scaled so that a system passing the sweep unchanged gives a unit impulse
+	let reference_peak = transforms::fft_convolve(&sweep, &inverse_filter).into_iter().fold(0.0, |peak: f64, value| peak.max(value.abs()));
+	let deconvolved = transforms::fft_convolve(system_output, &inverse_filter);
+	// linear response sits after the whole inverse filter, harmonic distortion products land before it
+	return deconvolved.into_iter().skip(sweep.len().saturating_sub(1)).map(|value| value / reference_peak).collect();
+}
\ No newline at end of file
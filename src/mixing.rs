@@ -0,0 +1,110 @@
+use crate::precision::Sample;
+
+/// How signals of different durations are combined
+#[derive(Clone, Copy, PartialEq)]
+pub enum LengthPolicy {
+	/// Result covers both signals, each one is zero outside of its own duration
+	ZeroPad,
+	/// Result covers only the part where both signals exist
+	Truncate,
+	/// Result covers both signals, the shorter one repeats to fill the duration of the longer one
+	Loop,
+}
+
+impl LengthPolicy {
+	pub fn from_name(name: &str) -> Option<Self> {
+		return match name {
+			"zero_pad" => Some(Self::ZeroPad),
+			"truncate" => Some(Self::Truncate),
+			"loop" => Some(Self::Loop),
+			_ => None,
+		};
+	}
+
+	pub fn get_name(&self) -> &'static str {
+		return match self {
+			Self::ZeroPad => "zero_pad",
+			Self::Truncate => "truncate",
+			Self::Loop => "loop",
+		};
+	}
+}
+
+/// How values of overlapping signals are combined into one
+#[derive(Clone, Copy, PartialEq)]
+pub enum CombineMode {
+	Sum,
+	Multiply,
+	Max,
+	Min,
+}
+
+impl CombineMode {
+	pub fn from_name(name: &str) -> Option<Self> {
+		return match name {
+			"sum" => Some(Self::Sum),
+			"multiply" => Some(Self::Multiply),
+			"max" => Some(Self::Max),
+			"min" => Some(Self::Min),
+			_ => None,
+		};
+	}
+
+	pub fn get_name(&self) -> &'static str {
+		return match self {
+			Self::Sum => "sum",
+			Self::Multiply => "multiply",
+			Self::Max => "max",
+			Self::Min => "min",
+		};
+	}
+
+	/// `a` and `b` combined in the precision they were computed in
+	pub fn combine<T: Sample>(&self, a: T, b: T) -> T {
+		return match self {
+			Self::Sum => a + b,
+			Self::Multiply => a * b,
+			Self::Max => a.max(b),
+			Self::Min => a.min(b),
+		};
+	}
+}
+
+/// Time range in s covered by the combination of signals occupying `windows` (start and end in s) according to `policy`
+pub fn covered_range(windows: &[(f64, f64)], policy: LengthPolicy) -> (f64, f64) {
+	if policy == LengthPolicy::Truncate {
+		return windows.iter().fold((f64::NEG_INFINITY, f64::INFINITY), |range, window| (range.0.max(window.0), range.1.min(window.1)));
+	}
+	return windows.iter().fold((f64::INFINITY, f64::NEG_INFINITY), |range, window| (range.0.min(window.0), range.1.max(window.1)));
+}
+
+/// Values at `points` of signal at `index` out of signals occupying `windows`, combined according to `policy`
+/// `evaluate` calculates values of the signal at given points
+pub fn evaluate_aligned<T: Sample>(points: &[f64], windows: &[(f64, f64)], index: usize, policy: LengthPolicy, evaluate: impl Fn(&[f64]) -> Vec<T>) -> Vec<T> {
+	let longest = windows.iter().map(|window| window.1 - window.0).fold(0.0, f64::max);
+	let window = windows[index];
+	let looped = policy == LengthPolicy::Loop && window.1 - window.0 < longest;
+	return evaluate_in_window(points, window, looped, evaluate);
+}
+
+/// Evaluates two signals occupying `window_a` and `window_b` (start and end in s) on `sampling_points` according to `policy`
+/// `evaluate_a` and `evaluate_b` calculate values of each signal at given points
+/// Returns the points covered by the result and values of both signals at them
+pub fn align_pair(sampling_points: &[f64], window_a: (f64, f64), window_b: (f64, f64), policy: LengthPolicy, evaluate_a: impl Fn(&[f64]) -> Vec<f64>, evaluate_b: impl Fn(&[f64]) -> Vec<f64>) -> (Vec<f64>, Vec<f64>, Vec<f64>) {
+	let windows = [window_a, window_b];
+	let covered = covered_range(&windows, policy);
+	let points: Vec<f64> = sampling_points.iter().copied().filter(|point| *point >= covered.0 && *point < covered.1).collect();
+	let values_a = evaluate_aligned(&points, &windows, 0, policy, evaluate_a);
+	let values_b = evaluate_aligned(&points, &windows, 1, policy, evaluate_b);
+	return (points, values_a, values_b);
+}
+
+/// Values of a signal occupying `window` at `points`, either zero outside of the window or repeated when `looped`
+fn evaluate_in_window<T: Sample>(points: &[f64], window: (f64, f64), looped: bool, evaluate: impl Fn(&[f64]) -> Vec<T>) -> Vec<T> {
+	let length = window.1 - window.0;
+	if looped && length > 0.0 {
+		let wrapped_points: Vec<f64> = points.iter().map(|point| window.0 + (point - window.0).rem_euclid(length)).collect();
+		return evaluate(&wrapped_points);
+	}
+	return points.iter().zip(evaluate(points)).map(|(point, value)| if *point >= window.0 && *point < window.1 { value } else { T::default() }).collect();
+}
\ No newline at end of file
@@ -0,0 +1,89 @@
+use crate::signals::CalculableSignal;
+
+#[derive(Clone)]
+pub struct Modulation {
+	/// Index of the signal whose parameter changes
+	pub target_index: usize,
+	/// Name of the changed parameter, as returned by `CalculableSignal::get_parameters`
+	pub parameter: String,
+	pub source: ModulationSource,
+}
+
+#[derive(Clone)]
+pub enum ModulationSource {
+	/// Stored signal used as a low frequency oscillator, added to the parameter value
+	Oscillator {
+		/// Index of the oscillator signal
+		source_index: usize,
+		/// Change of the parameter per unit of the oscillator value
+		depth: f64,
+	},
+	/// Breakpoints of (time in s, value) replacing the parameter value, linearly interpolated between them
+	Envelope(Vec<(f64, f64)>),
+}
+
+/// Calculates `signal` with parameters changing over time
+/// `parameter_values` holds the name and one value per sampling point of every modulated parameter
+pub fn calculate_modulated_signal(signal: &dyn CalculableSignal, sampling_points: &[f64], parameter_values: &[(String, Vec<f64>)]) -> Vec<crate::CoordPair> {
+	let mut modulated = signal.clone_box();
+	let amplitudes = parameter_values.iter().find(|(name, _)| name == "amplitude").map(|(_, values)| values);
+	if amplitudes.is_some() {
+		// calculated with unit amplitude and scaled afterwards, which works for every signal shape
+		modulated.set_parameter("amplitude", 1.0);
+	}
+
+	// changing frequency is done by warping time, so the phase stays continuous
+	let warped_points = match parameter_values.iter().find(|(name, _)| name == "signal_freq") {
+		Some((_, frequencies)) => {
+			let base_frequency = get_parameter(signal, "signal_freq").unwrap();
+			let mut warped_time = sampling_points.first().copied().unwrap_or(0.0);
+			let mut previous_point = warped_time;
+			sampling_points.iter().zip(frequencies).map(|(point, frequency)| {
+				warped_time += (point - previous_point) * frequency / base_frequency;
+				previous_point = *point;
+				return warped_time;
+			}).collect()
+		}
+		None => sampling_points.to_vec(),
+	};
+
+	let other_parameters: Vec<&(String, Vec<f64>)> = parameter_values.iter().filter(|(name, _)| name != "amplitude" && name != "signal_freq").collect();
+	let mut result = if other_parameters.is_empty() {
+		modulated.calculate_signal(&warped_points)
+	} else {
+		// remaining parameters have no general trick, so every point is calculated on its own
+		warped_points.iter().enumerate().map(|(index, point)| {
+			for (name, values) in &other_parameters {
+				modulated.set_parameter(name, values[index]);
+			}
+			return modulated.calculate_signal(&[*point]).remove(0);
+		}).collect()
+	};
+
+	for (index, point) in result.iter_mut().enumerate() {
+		point.x = sampling_points[index];
+		if let Some(amplitudes) = amplitudes {
+			point.y *= amplitudes[index];
+		}
+	}
+	return result;
+}
+
+pub fn get_parameter(signal: &dyn CalculableSignal, name: &str) -> Option<f64> {
+	return signal.get_parameters().into_iter().find(|(parameter, _)| *parameter == name).map(|(_, value)| value);
+}
+
+/// Value of the envelope at `time`. Before the first and after the last breakpoint the nearest value is held
+/// `breakpoints` need to be sorted by time
+pub fn evaluate_envelope(breakpoints: &[(f64, f64)], time: f64) -> f64 {
+	let next_index = breakpoints.partition_point(|(breakpoint_time, _)| *breakpoint_time <= time);
+	if next_index == 0 {
+		return breakpoints[0].1;
+	}
+	if next_index == breakpoints.len() {
+		return breakpoints[breakpoints.len() - 1].1;
+	}
+	let (previous_time, previous_value) = breakpoints[next_index - 1];
+	let (next_time, next_value) = breakpoints[next_index];
+	return previous_value + (next_value - previous_value) * (time - previous_time) / (next_time - previous_time);
+}
\ No newline at end of file
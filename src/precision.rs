@@ -0,0 +1,91 @@
+// Floating point types the evaluation of signals runs in, f64 normally and f32 in single precision mode
+// The whole pipeline is generic over `Sample`, so in single precision values are computed, combined and returned as f32
+
+use std::ops::{Add, Mul};
+
+use crate::signals::CalculableSignal;
+
+/// Values of a signal in the type they were computed in
+pub enum Samples {
+	Double(Vec<f64>),
+	Single(Vec<f32>),
+}
+
+impl Samples {
+	/// Values widened to f64, which is exact for both types
+	pub fn to_f64(&self) -> Vec<f64> {
+		return match self {
+			Self::Double(values) => values.clone(),
+			Self::Single(values) => values.iter().map(|value| *value as f64).collect(),
+		};
+	}
+
+	/// Values as f32, rounded when they were computed in double precision
+	pub fn to_f32(&self) -> Vec<f32> {
+		return match self {
+			Self::Double(values) => values.iter().map(|value| *value as f32).collect(),
+			Self::Single(values) => values.clone(),
+		};
+	}
+
+	pub fn len(&self) -> usize {
+		return match self {
+			Self::Double(values) => values.len(),
+			Self::Single(values) => values.len(),
+		};
+	}
+}
+
+/// f64 or f32, the type signals are evaluated and combined in
+pub trait Sample: Copy + Default + PartialOrd + Add<Output = Self> + Mul<Output = Self> + Send + Sync {
+	fn from_f64(value: f64) -> Self;
+	fn max(self, other: Self) -> Self;
+	fn min(self, other: Self) -> Self;
+	/// Values of `signal` at `sampling_points` computed in this type
+	fn calculate(signal: &dyn CalculableSignal, sampling_points: &[f64]) -> Vec<Self>;
+	fn into_samples(values: Vec<Self>) -> Samples;
+	/// `samples` in this type, unchanged when they were computed in it
+	fn from_samples(samples: &Samples) -> Vec<Self>;
+}
+
+impl Sample for f64 {
+	fn from_f64(value: f64) -> Self {
+		return value;
+	}
+	fn max(self, other: Self) -> Self {
+		return f64::max(self, other);
+	}
+	fn min(self, other: Self) -> Self {
+		return f64::min(self, other);
+	}
+	fn calculate(signal: &dyn CalculableSignal, sampling_points: &[f64]) -> Vec<Self> {
+		return signal.calculate_signal(sampling_points).into_iter().map(|point| point.y).collect();
+	}
+	fn into_samples(values: Vec<Self>) -> Samples {
+		return Samples::Double(values);
+	}
+	fn from_samples(samples: &Samples) -> Vec<Self> {
+		return samples.to_f64();
+	}
+}
+
+impl Sample for f32 {
+	fn from_f64(value: f64) -> Self {
+		return value as f32;
+	}
+	fn max(self, other: Self) -> Self {
+		return f32::max(self, other);
+	}
+	fn min(self, other: Self) -> Self {
+		return f32::min(self, other);
+	}
+	fn calculate(signal: &dyn CalculableSignal, sampling_points: &[f64]) -> Vec<Self> {
+		return signal.calculate_values_f32(sampling_points);
+	}
+	fn into_samples(values: Vec<Self>) -> Samples {
+		return Samples::Single(values);
+	}
+	fn from_samples(samples: &Samples) -> Vec<Self> {
+		return samples.to_f32();
+	}
+}
\ No newline at end of file
@@ -0,0 +1,41 @@
+#[cfg(feature = "wasm")]
+use wasm_bindgen::prelude::*;
+
+/// Error of computations stopped by their progress callback
+pub const CANCELLED: &str = "Computation was cancelled";
+
+#[cfg(feature = "wasm")]
+#[wasm_bindgen]
+extern "C" {
+	/// JS function called during long computations with the fraction of work done, from 0 to 1
+	/// Returning true cancels the computation, which then fails without changing anything
+	#[wasm_bindgen(typescript_type = "(progress: number) => boolean | void")]
+	pub type ProgressCallback;
+
+	#[wasm_bindgen(method, js_name = call)]
+	fn call(this: &ProgressCallback, context: &JsValue, progress: f64) -> JsValue;
+}
+
+/// Function called during long computations with the fraction of work done, from 0 to 1
+/// Returning true cancels the computation, which then fails without changing anything
+#[cfg(not(feature = "wasm"))]
+pub type ProgressCallback = Box<dyn FnMut(f64) -> bool>;
+
+/// Progress function for the internals out of an optional `callback`, true means go on
+/// The callback is only called when progress moved by at least 1% since the last call, and at the end
+pub fn reporter(mut callback: Option<ProgressCallback>) -> impl FnMut(f64) -> bool {
+	let mut last_reported = f64::NEG_INFINITY;
+	return move |fraction| {
+		let Some(callback) = &mut callback else {
+			return true;
+		};
+		if fraction < 1.0 && fraction - last_reported < 0.01 {
+			return true;
+		}
+		last_reported = fraction;
+		#[cfg(feature = "wasm")]
+		return !callback.call(&JsValue::NULL, fraction).is_truthy();
+		#[cfg(not(feature = "wasm"))]
+		return !callback(fraction);
+	};
+}
\ No newline at end of file
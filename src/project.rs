@@ -0,0 +1,214 @@
+use crate::json::JsonValue;
+use crate::mixing::{CombineMode, LengthPolicy};
+use crate::modulation::{Modulation, ModulationSource};
+use crate::signals::{self, CalculableSignal};
+use crate::SignalProcessor;
+
+/// Version of the project format written by `save`
+/// Bump it whenever a change can't be covered by defaults for missing fields and teach `migrate` how to upgrade the previous version
+pub const PROJECT_VERSION: u32 = 1;
+
+pub fn save(processor: &SignalProcessor) -> String {
+	let signals = (0..processor.signals.len()).map(|index| save_stored_signal(processor, index)).collect();
+
+	let modulations = processor.modulations.iter().map(|modulation| {
+		let mut entries = vec![
+			("target".to_string(), JsonValue::Number(modulation.target_index as f64)),
+			("parameter".to_string(), JsonValue::String(modulation.parameter.clone())),
+		];
+		match &modulation.source {
+			ModulationSource::Oscillator { source_index, depth } => {
+				entries.push(("source".to_string(), JsonValue::Number(*source_index as f64)));
+				entries.push(("depth".to_string(), JsonValue::Number(*depth)));
+			}
+			ModulationSource::Envelope(breakpoints) => {
+				entries.push(("envelope".to_string(), JsonValue::Array(breakpoints.iter().map(|(time, value)| number_array(&[*time, *value])).collect())));
+			}
+		}
+		return JsonValue::Object(entries);
+	}).collect();
+
+	let mut entries = vec![
+		("version".to_string(), JsonValue::Number(PROJECT_VERSION as f64)),
+		("sampling_frequency".to_string(), JsonValue::Number(processor.sampling_frequency)),
+		("starting_time".to_string(), JsonValue::Number(processor.starting_time)),
+		("length_policy".to_string(), JsonValue::String(processor.length_policy.get_name().to_string())),
+		("combine_mode".to_string(), JsonValue::String(processor.combine_mode.get_name().to_string())),
+		("jitter_rms".to_string(), JsonValue::Number(processor.jitter_rms)),
+		("oversampling".to_string(), JsonValue::Number(processor.oversampling as f64)),
+		("single_precision".to_string(), JsonValue::Bool(processor.single_precision)),
+		("signals".to_string(), JsonValue::Array(signals)),
+		("modulations".to_string(), JsonValue::Array(modulations)),
+	];
+	if let Some(seed) = processor.seed {
+		// written as a string, because numbers above 2^53 don't survive as JSON numbers
+		entries.push(("seed".to_string(), JsonValue::String(seed.to_string())));
+	}
+	return JsonValue::Object(entries).stringify();
+}
+
+/// Restores a processor saved by this or any older version
+/// Parameters missing from older projects keep the defaults of `signals::create_signal`
+pub fn load(text: &str) -> Result<SignalProcessor, String> {
+	let document = crate::json::parse(text)?;
+	let version = document.get("version").and_then(JsonValue::as_f64).ok_or("Project has no version")? as u32;
+	let project = migrate(document, version)?;
+
+	let mut processor = SignalProcessor::with_sampling(
+		project.get("sampling_frequency").and_then(JsonValue::as_f64).ok_or("Project has no sampling frequency")?,
+		project.get("starting_time").and_then(JsonValue::as_f64).unwrap_or(0.0),
+	)?;
+	if let Some(policy) = project.get("length_policy").and_then(JsonValue::as_str) {
+		processor.length_policy = LengthPolicy::from_name(policy).ok_or(format!("Unknown length policy {}", policy))?;
+	}
+	if let Some(mode) = project.get("combine_mode").and_then(JsonValue::as_str) {
+		processor.combine_mode = CombineMode::from_name(mode).ok_or(format!("Unknown combine mode {}", mode))?;
+	}
+	processor.jitter_rms = project.get("jitter_rms").and_then(JsonValue::as_f64).unwrap_or(0.0);
+	processor.single_precision = project.get("single_precision").and_then(JsonValue::as_bool).unwrap_or(false);
+	processor.oversampling = project.get("oversampling").and_then(JsonValue::as_f64).map_or(1, |factor| (factor as usize).max(1));
+	if let Some(seed) = project.get("seed") {
+		processor.seed = Some(seed.as_str().and_then(|seed| seed.parse().ok()).ok_or("Seed is not an integer string")?);
+	}
+	for entry in project.get("signals").and_then(JsonValue::as_array).unwrap_or(&[]) {
+		let id = processor.insert_signal(load_signal(entry)?);
+		// projects from before signals had ids keep the ones given in order
+		if let Some(saved_id) = entry.get("id").and_then(JsonValue::as_f64) {
+			if processor.signal_ids.contains(&(saved_id as u32)) && saved_id as u32 != id {
+				return Err(format!("Signal id {} is used twice", saved_id));
+			}
+			*processor.signal_ids.last_mut().unwrap() = saved_id as u32;
+			processor.next_signal_id = processor.next_signal_id.max(saved_id as u32 + 1);
+		}
+		let id = *processor.signal_ids.last().unwrap();
+		if entry.get("enabled").and_then(JsonValue::as_bool) == Some(false) {
+			processor.disabled_ids.push(id);
+		}
+		for (key, value) in entry.get("metadata").and_then(JsonValue::as_object).unwrap_or(&[]) {
+			let value = value.as_str().ok_or(format!("Metadata {} is not a string", key))?;
+			processor.metadata.push((id, key.clone(), value.to_string()));
+		}
+	}
+	for entry in project.get("modulations").and_then(JsonValue::as_array).unwrap_or(&[]) {
+		let target_index = index_field(entry, "target", processor.signals.len())?;
+		let parameter = entry.get("parameter").and_then(JsonValue::as_str).ok_or("Modulation has no parameter")?.to_string();
+		let source = match entry.get("envelope") {
+			Some(envelope) => ModulationSource::Envelope(envelope.as_array().ok_or("Envelope is not an array")?.iter().map(|breakpoint| {
+				let pair = number_vec(breakpoint)?;
+				if pair.len() != 2 {
+					return Err("Envelope breakpoint needs a time and a value".to_string());
+				}
+				return Ok((pair[0], pair[1]));
+			}).collect::<Result<Vec<_>, String>>()?),
+			None => ModulationSource::Oscillator {
+				source_index: index_field(entry, "source", processor.signals.len())?,
+				depth: entry.get("depth").and_then(JsonValue::as_f64).ok_or("Modulation has no depth")?,
+			},
+		};
+		processor.modulations.push(Modulation {
+			target_index,
+			parameter,
+			source,
+		});
+	}
+	return Ok(processor);
+}
+
+/// `save_signal` of signal at `index` of `processor` with its id, metadata and whether it is disabled
+pub fn save_stored_signal(processor: &SignalProcessor, index: usize) -> JsonValue {
+	let id = processor.signal_ids[index];
+	let mut entry = save_signal(processor.signals[index].as_ref());
+	if let JsonValue::Object(entries) = &mut entry {
+		entries.push(("id".to_string(), JsonValue::Number(id as f64)));
+		if processor.disabled_ids.contains(&id) {
+			entries.push(("enabled".to_string(), JsonValue::Bool(false)));
+		}
+		let metadata: Vec<(String, JsonValue)> = processor.metadata.iter().filter(|(signal_id, _, _)| *signal_id == id).map(|(_, key, value)| (key.clone(), JsonValue::String(value.clone()))).collect();
+		if !metadata.is_empty() {
+			entries.push(("metadata".to_string(), JsonValue::Object(metadata)));
+		}
+	}
+	return entry;
+}
+
+/// Type, parameters and any stored data of `signal`
+pub fn save_signal(signal: &dyn CalculableSignal) -> JsonValue {
+	let mut entries = vec![
+		("type".to_string(), JsonValue::String(signal.get_type_name().to_string())),
+		("parameters".to_string(), JsonValue::Object(signal.get_parameters().into_iter().map(|(name, value)| (name.to_string(), JsonValue::Number(value))).collect())),
+	];
+	if let Some(samples) = signal.get_samples() {
+		entries.push(("samples".to_string(), number_array(samples)));
+	}
+	if let Some(text) = signal.get_text() {
+		entries.push(("text".to_string(), JsonValue::String(text.to_string())));
+	}
+	let operands = signal.get_operands();
+	if !operands.is_empty() {
+		entries.push(("operands".to_string(), JsonValue::Array(operands.into_iter().map(save_signal).collect())));
+	}
+	return JsonValue::Object(entries);
+}
+
+fn load_signal(entry: &JsonValue) -> Result<Box<dyn CalculableSignal>, String> {
+	let type_name = entry.get("type").and_then(JsonValue::as_str).ok_or("Signal has no type")?;
+	let samples = entry.get("samples").map(number_vec).transpose()?.unwrap_or_default();
+	let text = entry.get("text").and_then(JsonValue::as_str).unwrap_or_default().to_string();
+	let operands = entry.get("operands").and_then(JsonValue::as_array).unwrap_or(&[]).iter().map(load_signal).collect::<Result<Vec<_>, String>>()?;
+	let parameters = entry.get("parameters").and_then(JsonValue::as_object).unwrap_or(&[]).iter().map(|(name, value)| {
+		return Ok((name.as_str(), value.as_f64().ok_or(format!("Parameter {} is not a number", name))?));
+	}).collect::<Result<Vec<_>, String>>()?;
+	return build_signal(type_name, samples, text, operands, &parameters);
+}
+
+/// Signal from a flat config object like {"type": "sine", "signal_freq": 5, "amplitude": 2}
+/// Every key except "type", "samples" and "text" is a parameter, parameters not listed keep the defaults of `signals::create_signal`
+pub fn signal_from_config(config: &JsonValue) -> Result<Box<dyn CalculableSignal>, String> {
+	let entries = config.as_object().ok_or("Signal config has to be a JSON object")?;
+	let type_name = config.get("type").and_then(JsonValue::as_str).ok_or("Signal config has no type")?;
+	let samples = config.get("samples").map(number_vec).transpose()?.unwrap_or_default();
+	let text = config.get("text").and_then(JsonValue::as_str).unwrap_or_default().to_string();
+	let parameters = entries.iter().filter(|(name, _)| !matches!(name.as_str(), "type" | "samples" | "text")).map(|(name, value)| {
+		return Ok((name.as_str(), value.as_f64().ok_or(format!("Parameter {} is not a number", name))?));
+	}).collect::<Result<Vec<_>, String>>()?;
+	return build_signal(type_name, samples, text, Vec::new(), &parameters);
+}
+
+/// Signal of `type_name` created by `signals::create_signal` with `parameters` set on it
+pub fn build_signal(type_name: &str, samples: Vec<f64>, text: String, operands: Vec<Box<dyn CalculableSignal>>, parameters: &[(&str, f64)]) -> Result<Box<dyn CalculableSignal>, String> {
+	let mut signal = signals::create_signal(type_name, samples, text, operands).ok_or(format!("Invalid signal of type {}", type_name))?;
+	for (name, value) in parameters {
+		signals::validate_parameter(name, *value)?;
+		if !signal.set_parameter(name, *value) {
+			return Err(format!("Signal type {} has no parameter {}", type_name, name));
+		}
+	}
+	return Ok(signal);
+}
+
+/// Upgrades `project` saved with `version` step by step to `PROJECT_VERSION`
+fn migrate(project: JsonValue, version: u32) -> Result<JsonValue, String> {
+	if version > PROJECT_VERSION {
+		return Err(format!("Project version {} is newer than supported version {}", version, PROJECT_VERSION));
+	}
+	if version == 0 {
+		return Err("Project version 0 does not exist".to_string());
+	}
+	return Ok(project);
+}
+
+fn number_array(values: &[f64]) -> JsonValue {
+	return JsonValue::Array(values.iter().map(|value| JsonValue::Number(*value)).collect());
+}
+
+fn number_vec(value: &JsonValue) -> Result<Vec<f64>, String> {
+	return value.as_array().ok_or("Expected an array of numbers")?.iter().map(|element| element.as_f64().ok_or("Expected a number".to_string())).collect();
+}
+
+fn index_field(entry: &JsonValue, key: &str, signal_count: usize) -> Result<usize, String> {
+	let index = entry.get(key).and_then(JsonValue::as_f64).ok_or(format!("Missing signal index {}", key))? as usize;
+	if index >= signal_count {
+		return Err(format!("Signal index {} is out of range", index));
+	}
+	return Ok(index);
+}
\ No newline at end of file
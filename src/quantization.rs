@@ -0,0 +1,96 @@
+#[cfg(feature = "wasm")]
+use wasm_bindgen::prelude::*;
+
+use crate::CoordPair;
+
+/// Signal quantized to a number of evenly spaced levels, along with the error it introduced
+#[cfg_attr(feature = "wasm", wasm_bindgen)]
+pub struct Quantization {
+	times: Vec<f64>,
+	quantized: Vec<f64>,
+	errors: Vec<f64>,
+	/// Distance between neighbouring levels
+	pub step: f64,
+}
+
+#[cfg_attr(feature = "wasm", wasm_bindgen)]
+impl Quantization {
+	/// Quantized signal versus time in s
+	pub fn quantized(&self) -> Vec<CoordPair> {
+		return pairs(&self.times, &self.quantized);
+	}
+
+	/// Quantized signal minus the original one versus time in s
+	pub fn error(&self) -> Vec<CoordPair> {
+		return pairs(&self.times, &self.errors);
+	}
+}
+
+/// μ of the μ-law used in North American and Japanese telephony
+const MU: f64 = 255.0;
+/// A of the A-law used in European telephony
+const A: f64 = 87.6;
+
+/// Quantizes `values` sampled at `times` to 2^`bits` levels spread evenly from their minimum to their maximum
+/// `mode` is "rounding" to the nearest level or "truncation" to the level below
+/// `law` other than "linear" compresses the values before quantizing and expands them after, see `compand`,
+/// so the levels are spread evenly in the compressed domain and `step` is measured there
+pub fn quantize(times: Vec<f64>, values: &[f64], bits: u32, mode: &str, law: &str) -> Result<Quantization, String> {
+	if bits == 0 || bits > 52 {
+		return Err(format!("Bit depth must be between 1 and 52, got {}", bits));
+	}
+	let full_scale = values.iter().fold(0.0, |peak: f64, value| peak.max(value.abs()));
+	let compressed = compand(values, law, false, full_scale)?;
+	let (quantized, step) = quantize_uniformly(&compressed, bits, mode)?;
+	let quantized = compand(&quantized, law, true, full_scale)?;
+	let errors = quantized.iter().zip(values).map(|(quantized, value)| quantized - value).collect();
+	return Ok(Quantization {
+		times,
+		quantized,
+		errors,
+		step,
+	});
+}
+
+/// Compresses `values` with companding `law`, "linear" leaving them be, "mu_law" (μ = 255) or "a_law" (A = 87.6),
+/// or expands them back with `inverse`. `full_scale` maps to 1 of the law, values beyond it saturate
+pub fn compand(values: &[f64], law: &str, inverse: bool, full_scale: f64) -> Result<Vec<f64>, String> {
+	let curve: fn(f64, bool) -> f64 = match law {
+		"linear" => |value, _| value,
+		"mu_law" => |value, inverse| {
+			return if inverse { ((1.0 + MU).powf(value) - 1.0) / MU } else { (1.0 + MU * value).ln() / (1.0 + MU).ln() };
+		},
+		"a_law" => |value, inverse| {
+			let scale = 1.0 + A.ln();
+			return match inverse {
+				false if value < 1.0 / A => A * value / scale,
+				false => (1.0 + (A * value).ln()) / scale,
+				true if value < 1.0 / scale => value * scale / A,
+				true => (value * scale - 1.0).exp() / A,
+			};
+		},
+		_ => return Err(format!("Unknown companding law {}", law)),
+	};
+	if full_scale <= 0.0 {
+		return Ok(values.to_vec());
+	}
+	// laws are defined for magnitudes up to 1 and keep the sign
+	return Ok(values.iter().map(|value| curve((value.abs() / full_scale).min(1.0), inverse).copysign(*value) * full_scale).collect());
+}
+
+/// `values` rounded with `mode` to 2^`bits` levels spread evenly from their minimum to their maximum, with the distance between levels
+fn quantize_uniformly(values: &[f64], bits: u32, mode: &str) -> Result<(Vec<f64>, f64), String> {
+	let low = values.iter().copied().fold(f64::INFINITY, f64::min);
+	let high = values.iter().copied().fold(f64::NEG_INFINITY, f64::max);
+	let step = if high > low { (high - low) / (2.0_f64.powi(bits as i32) - 1.0) } else { 1.0 };
+	let round: fn(f64) -> f64 = match mode {
+		"rounding" => f64::round,
+		"truncation" => f64::floor,
+		_ => return Err(format!("Unknown quantization mode {}", mode)),
+	};
+	return Ok((values.iter().map(|value| low + round((value - low) / step) * step).collect(), step));
+}
+
+fn pairs(times: &[f64], values: &[f64]) -> Vec<CoordPair> {
+	return times.iter().zip(values).map(|(x, y)| CoordPair { x: *x, y: *y }).collect();
+}
\ No newline at end of file
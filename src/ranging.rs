@@ -0,0 +1,71 @@
+use rand::{rngs::StdRng, Rng};
+#[cfg(feature = "wasm")]
+use wasm_bindgen::prelude::*;
+
+use crate::resampling;
+use crate::transforms;
+use crate::CoordPair;
+
+/// Echo of a probe signal returned by a target and the distance estimated from it
+#[cfg_attr(feature = "wasm", wasm_bindgen)]
+pub struct EchoSimulation {
+	times: Vec<f64>,
+	received: Vec<f64>,
+	/// Cross-correlation of the received signal with the probe for every lag in samples from 0
+	correlation: Vec<f64>,
+	sampling_frequency: f64,
+	/// Round trip time in s found from the cross-correlation peak
+	pub estimated_delay: f64,
+	/// Distance to the target in m found from the estimated delay
+	pub estimated_distance: f64,
+}
+
+#[cfg_attr(feature = "wasm", wasm_bindgen)]
+impl EchoSimulation {
+	/// Received signal versus time in s, starting with the emission of the probe
+	pub fn received(&self) -> Vec<CoordPair> {
+		return self.times.iter().zip(&self.received).map(|(x, y)| CoordPair { x: *x, y: *y }).collect();
+	}
+
+	/// Cross-correlation of the received signal with the probe versus lag in s
+	pub fn correlation(&self) -> Vec<CoordPair> {
+		return self.correlation.iter().enumerate().map(|(lag, y)| CoordPair { x: lag as f64 / self.sampling_frequency, y: *y }).collect();
+	}
+}
+
+/// Emits `probe` sampled at `sampling_frequency` from `start_time` towards a target `distance` m away, with waves travelling at `speed` m/s
+/// The echo arrives after the round trip, attenuated by two-way spherical spreading as 1 / distance^2 relative to 1 m,
+/// and is received with uniform noise between -`noise_amplitude` and `noise_amplitude` drawn from `rng`
+pub fn simulate_echo(probe: &[f64], start_time: f64, sampling_frequency: f64, distance: f64, speed: f64, noise_amplitude: f64, rng: &mut StdRng) -> EchoSimulation {
+	let delay = 2.0 * distance / speed;
+	let attenuation = 1.0 / distance.max(1.0).powi(2);
+	let length = probe.len() + (delay * sampling_frequency).ceil() as usize;
+	let times: Vec<f64> = (0..length).map(|index| start_time + index as f64 / sampling_frequency).collect();
+	// fractional delays land between samples, so the echo is interpolated from the probe
+	let delayed_times: Vec<f64> = times.iter().map(|time| time - delay).collect();
+	let received: Vec<f64> = resampling::resample(probe, start_time, sampling_frequency, &delayed_times, "linear").into_iter().map(|value| {
+		return attenuation * value + if noise_amplitude > 0.0 { rng.gen_range(-noise_amplitude..=noise_amplitude) } else { 0.0 };
+	}).collect();
+
+	// correlating with the time reversed probe is a convolution, whose output at index probe.len() - 1 is lag 0
+	let reversed: Vec<f64> = probe.iter().rev().copied().collect();
+	let correlation: Vec<f64> = transforms::fft_convolve(&received, &reversed).into_iter().skip(probe.len() - 1).take(length).collect();
+	let peak = (0..correlation.len()).max_by(|a, b| correlation[*a].total_cmp(&correlation[*b])).unwrap_or(0);
+	// parabola through the peak and its neighbours places it between samples
+	let offset = if peak > 0 && peak + 1 < correlation.len() {
+		let (left, center, right) = (correlation[peak - 1], correlation[peak], correlation[peak + 1]);
+		let curvature = left - 2.0 * center + right;
+		if curvature < 0.0 { 0.5 * (left - right) / curvature } else { 0.0 }
+	} else {
+		0.0
+	};
+	let estimated_delay = (peak as f64 + offset) / sampling_frequency;
+	return EchoSimulation {
+		times,
+		received,
+		correlation,
+		sampling_frequency,
+		estimated_delay,
+		estimated_distance: estimated_delay * speed / 2.0,
+	};
+}
\ No newline at end of file
@@ -0,0 +1,80 @@
+use std::f64::consts::PI;
+
+#[cfg(feature = "wasm")]
+use wasm_bindgen::prelude::*;
+
+use crate::CoordPair;
+
+/// Samples on each side of a point summed by sinc reconstruction when the caller doesn't choose
+pub const DEFAULT_SINC_NEIGHBOURS: usize = 32;
+
+/// Signal sampled at a different rate and reconstructed back on its original sampling points
+#[cfg_attr(feature = "wasm", wasm_bindgen)]
+pub struct AliasingDemonstration {
+	original: Vec<CoordPair>,
+	sample_times: Vec<f64>,
+	samples: Vec<f64>,
+	reconstructed: Vec<f64>,
+}
+
+#[cfg_attr(feature = "wasm", wasm_bindgen)]
+impl AliasingDemonstration {
+	/// Signal at its original sampling points
+	pub fn original(&self) -> Vec<CoordPair> {
+		return self.original.iter().map(|point| CoordPair { x: point.x, y: point.y }).collect();
+	}
+
+	/// Samples taken at the new sampling frequency
+	pub fn samples(&self) -> Vec<CoordPair> {
+		return self.sample_times.iter().zip(&self.samples).map(|(x, y)| CoordPair { x: *x, y: *y }).collect();
+	}
+
+	/// Signal reconstructed from the samples at the original sampling points
+	pub fn reconstructed(&self) -> Vec<CoordPair> {
+		return self.original.iter().zip(&self.reconstructed).map(|(point, y)| CoordPair { x: point.x, y: *y }).collect();
+	}
+}
+
+impl AliasingDemonstration {
+	pub fn new(original: Vec<CoordPair>, sample_times: Vec<f64>, samples: Vec<f64>, reconstructed: Vec<f64>) -> Self {
+		return Self {
+			original,
+			sample_times,
+			samples,
+			reconstructed,
+		};
+	}
+}
+
+/// Reconstructs a continuous signal from `samples` taken every 1 / `sampling_frequency` s starting at `start_time`, evaluated at `points`
+/// `method` is "zero_order_hold", which holds each sample until the next one, "first_order_hold", which joins samples with lines,
+/// or "sinc", the Whittaker-Shannon interpolation truncated to `sinc_neighbours` samples on each side
+/// Points before the first sample or a sampling period after the last one are zero
+pub fn reconstruct(samples: &[f64], start_time: f64, sampling_frequency: f64, points: &[f64], method: &str, sinc_neighbours: usize) -> Result<Vec<f64>, String> {
+	if !matches!(method, "zero_order_hold" | "first_order_hold" | "sinc") {
+		return Err(format!("Unknown reconstruction method {}", method));
+	}
+	return Ok(points.iter().map(|point| {
+		let position = (point - start_time) * sampling_frequency;
+		if position < 0.0 || position >= samples.len() as f64 {
+			return 0.0;
+		}
+		let earlier = position.floor() as usize;
+		let fraction = position - earlier as f64;
+		return match method {
+			"zero_order_hold" => samples[earlier],
+			"first_order_hold" => {
+				let later = (earlier + 1).min(samples.len() - 1);
+				samples[earlier] * (1.0 - fraction) + samples[later] * fraction
+			}
+			_ => {
+				let first = (earlier + 1).saturating_sub(sinc_neighbours.max(1));
+				let last = (earlier + sinc_neighbours.max(1)).min(samples.len() - 1);
+				(first..=last).map(|index| {
+					let offset = position - index as f64;
+					return samples[index] * if offset == 0.0 { 1.0 } else { (PI * offset).sin() / (PI * offset) };
+				}).sum()
+			}
+		};
+	}).collect());
+}
\ No newline at end of file
@@ -0,0 +1,132 @@
+use std::f64::consts::PI;
+
+use crate::filters;
+use crate::progress::CANCELLED;
+use crate::windows;
+
+/// Zero crossings of the sinc kernel of `resample_bandlimited` on each side of its center
+const SINC_ZERO_CROSSINGS: f64 = 16.0;
+/// β of the Kaiser window tapering that kernel
+const SINC_KAISER_BETA: f64 = 8.6;
+
+/// Evaluates samples taken every `1 / sampling_frequency` s starting at `start_time` at arbitrary `points`
+/// `method` is "nearest", "linear" or "cubic" (Catmull-Rom spline through the samples). Points outside of the sampled range are zero
+pub fn resample(samples: &[f64], start_time: f64, sampling_frequency: f64, points: &[f64], method: &str) -> Vec<f64> {
+	return points.iter().map(|point| {
+		let position = (point - start_time) * sampling_frequency;
+		if samples.is_empty() || position < -0.5 || position > samples.len() as f64 - 0.5 {
+			return 0.0;
+		}
+		return match method {
+			"nearest" => samples[(position.round() as usize).min(samples.len() - 1)],
+			"linear" => {
+				let position = position.clamp(0.0, (samples.len() - 1) as f64);
+				let earlier = position.floor() as usize;
+				let later = (earlier + 1).min(samples.len() - 1);
+				let fraction = position - earlier as f64;
+				samples[earlier] * (1.0 - fraction) + samples[later] * fraction
+			}
+			"cubic" => {
+				let position = position.clamp(0.0, (samples.len() - 1) as f64);
+				let earlier = position.floor() as usize;
+				let fraction = position - earlier as f64;
+				// samples beyond the ends repeat the edge values
+				let sample = |offset: isize| samples[(earlier as isize + offset).clamp(0, samples.len() as isize - 1) as usize];
+				let (p0, p1, p2, p3) = (sample(-1), sample(0), sample(1), sample(2));
+				p1 + 0.5 * fraction * (p2 - p0 + fraction * (2.0 * p0 - 5.0 * p1 + 4.0 * p2 - p3 + fraction * (3.0 * (p1 - p2) + p3 - p0)))
+			}
+			_ => panic!("Unknown resampling method {}", method),
+		};
+	}).collect();
+}
+
+/// Whether `resample` accepts `method`
+pub fn is_method(method: &str) -> bool {
+	return matches!(method, "nearest" | "linear" | "cubic");
+}
+
+/// Keeps every `factor`th sample of `samples`, starting with the first one
+/// With `filter_order` above 0 they are first lowpass filtered below the new Nyquist frequency by a Hamming windowed sinc FIR
+/// of `filter_order` rounded up to even, centered so it doesn't delay the signal. Order 0 lets everything alias
+pub fn decimate(samples: &[f64], factor: usize, filter_order: usize) -> Vec<f64> {
+	let factor = factor.max(1);
+	if filter_order == 0 || factor == 1 {
+		return samples.iter().step_by(factor).copied().collect();
+	}
+	let taps = filter_order.div_ceil(2) * 2 + 1;
+	let kernel = filters::fir_lowpass(0.5 / factor as f64, &windows::create("hamming", taps).unwrap());
+	return (0..samples.len()).step_by(factor).map(|index| filters::centered_fir_at(samples, &kernel, index)).collect();
+}
+
+/// Raises the sampling frequency of `samples` `factor` times by inserting `factor` - 1 zeros after each sample and filtering the result
+/// `method` picks the interpolation filter: "hold" repeats each sample, "linear" joins samples with lines and "sinc" is a Hamming
+/// windowed sinc lowpass spanning 8 original samples on each side. Only the products with original samples are computed, which makes it a polyphase filter
+pub fn interpolate(samples: &[f64], factor: usize, method: &str) -> Result<Vec<f64>, String> {
+	let factor = factor.max(1);
+	// kernel with the index of its sample aligned with each original sample
+	let (kernel, center): (Vec<f64>, usize) = match method {
+		"hold" => (vec![1.0; factor], 0),
+		"linear" => ((1..2 * factor).map(|index| 1.0 - index.abs_diff(factor) as f64 / factor as f64).collect(), factor - 1),
+		"sinc" => {
+			let taps = 16 * factor + 1;
+			let kernel = filters::fir_lowpass(0.5 / factor as f64, &windows::create("hamming", taps).unwrap());
+			// zeros take away all but 1 / factor of the signal, the gain brings it back
+			(kernel.into_iter().map(|coefficient| coefficient * factor as f64).collect(), 8 * factor)
+		}
+		_ => return Err(format!("Unknown interpolation method {}", method)),
+	};
+	return Ok((0..samples.len() * factor).map(|index| {
+		// original samples m reaching output `index` satisfy 0 <= index - m * factor + center < kernel length
+		let shifted = index + center;
+		let first = (shifted + 1).saturating_sub(kernel.len()).div_ceil(factor);
+		let last = (shifted / factor).min(samples.len() - 1);
+		return (first..=last).map(|original| samples[original] * kernel[shifted - original * factor]).sum();
+	}).collect());
+}
+
+/// Resamples `samples` taken at `sampling_frequency` to `target_frequency`, any ratio of the two, by evaluating
+/// a Kaiser windowed sinc kernel between the original samples. When lowering the rate the kernel is widened,
+/// so it also removes everything above the new Nyquist frequency. The result covers the same time span
+pub fn resample_bandlimited(samples: &[f64], sampling_frequency: f64, target_frequency: f64) -> Vec<f64> {
+	return resample_bandlimited_reporting(samples, sampling_frequency, target_frequency, &mut |_| true).unwrap_or_default();
+}
+
+/// `resample_bandlimited` calling `progress` with the fraction of output computed, stopping with an error once it returns false
+pub fn resample_bandlimited_reporting(samples: &[f64], sampling_frequency: f64, target_frequency: f64, progress: &mut dyn FnMut(f64) -> bool) -> Result<Vec<f64>, String> {
+	let ratio = (target_frequency / sampling_frequency).min(1.0);
+	let reach = SINC_ZERO_CROSSINGS / ratio;
+	let length = (samples.len() as f64 * target_frequency / sampling_frequency).floor() as usize;
+	return (0..length).map(|index| {
+		if !progress((index + 1) as f64 / length as f64) {
+			return Err(CANCELLED.to_string());
+		}
+		let position = index as f64 * sampling_frequency / target_frequency;
+		let first = (position - reach).ceil().max(0.0) as usize;
+		let last = ((position + reach).floor() as usize).min(samples.len().saturating_sub(1));
+		return Ok((first..=last).map(|original| {
+			let offset = position - original as f64;
+			let sinc = if offset == 0.0 { 1.0 } else { (PI * ratio * offset).sin() / (PI * ratio * offset) };
+			return samples[original] * ratio * sinc * windows::kaiser_at(offset / reach, SINC_KAISER_BETA);
+		}).sum());
+	}).collect();
+}
+
+#[cfg(test)]
+mod tests {
+	use std::f64::consts::TAU;
+
+	use super::*;
+
+	#[test]
+	fn resampled_tone_keeps_its_frequency() {
+		let tone = |sampling_frequency: f64, length: usize| (0..length).map(|index| (TAU * 50.0 * index as f64 / sampling_frequency).sin()).collect::<Vec<f64>>();
+		for target_frequency in [1500.0, 700.0] {
+			let resampled = resample_bandlimited(&tone(1000.0, 1000), 1000.0, target_frequency);
+			assert_eq!(resampled.len(), target_frequency as usize);
+			let expected = tone(target_frequency, resampled.len());
+			// edges lack samples on one side of the kernel
+			let middle = resampled.len() / 10..resampled.len() * 9 / 10;
+			assert!(resampled[middle.clone()].iter().zip(&expected[middle]).all(|(value, expected)| (value - expected).abs() < 1e-2), "at {} Hz", target_frequency);
+		}
+	}
+}
\ No newline at end of file
@@ -1,12 +1,171 @@
 use std::f64::consts::TAU;
+use std::hash::{Hash, Hasher};
 
-use rand::{distributions::Distribution, Rng};
+use rand::{distributions::Distribution, rngs::StdRng, SeedableRng};
 
-pub trait CalculableSignal {
+/// Number of random rows summed by pink noise, which is flat down to sampling frequency / 2^rows
+const PINK_NOISE_ROWS: usize = 16;
+/// Standard deviation of a single step of Brownian noise relative to its bounds
+const BROWNIAN_NOISE_STEP: f64 = 0.02;
+
+pub trait CalculableSignal: Send + Sync {
+	/// Values at `sampling_points`, which may be outside of the signal duration. The processor zeroes those
 	fn calculate_signal(&self, sampling_points: &[f64]) -> Vec<crate::CoordPair>;
+	/// Values at `sampling_points` computed in single precision, rounded double precision values for signals without their own f32 formula
+	fn calculate_values_f32(&self, sampling_points: &[f64]) -> Vec<f32> {
+		return self.calculate_signal(sampling_points).into_iter().map(|point| point.y as f32).collect();
+	}
+	/// Start of the signal in s relative to global starting point
+	fn get_signal_start(&self) -> f64;
 	fn get_signal_end(&self) -> f64;
+	/// Names and current values of all numeric parameters of the signal
+	fn get_parameters(&self) -> Vec<(&'static str, f64)>;
+	/// Changes value of parameter `name`, returns false if the signal has no such parameter
+	fn set_parameter(&mut self, name: &str, value: f64) -> bool;
+	fn clone_box(&self) -> Box<dyn CalculableSignal>;
+	/// Name identifying the kind of signal in saved projects
+	fn get_type_name(&self) -> &'static str;
+	/// Raw values for signals that store them instead of calculating
+	fn get_samples(&self) -> Option<&[f64]> {
+		return None;
+	}
+	/// Text the signal was generated from or its textual setting, like the interpolation method
+	fn get_text(&self) -> Option<&str> {
+		return None;
+	}
+	/// Signals combined by signals built from other signals
+	fn get_operands(&self) -> Vec<&dyn CalculableSignal> {
+		return Vec::new();
+	}
+	/// Copy of a random signal which always gives the same values for `seed`, None for signals that aren't random
+	fn with_seed(&self, _seed: u64) -> Option<Box<dyn CalculableSignal>> {
+		return None;
+	}
+	/// Period in s of signals repeating forever within their duration, None for the rest
+	fn get_period(&self) -> Option<f64> {
+		return None;
+	}
+	/// Whether the value at a point depends only on the point, so points can be evaluated in separate pieces
+	/// False for signals looking at neighbouring points or drawing a random sequence through all of them
+	fn is_pointwise(&self) -> bool {
+		return true;
+	}
+}
+
+impl Clone for Box<dyn CalculableSignal> {
+	fn clone(&self) -> Self {
+		return self.clone_box();
+	}
+}
+
+/// Independent seed for `stream` out of many drawing from the same `seed`
+pub fn derive_seed(seed: u64, stream: u64) -> u64 {
+	return seed ^ stream.wrapping_add(1).wrapping_mul(0x9e37_79b9_7f4a_7c15);
+}
+
+/// Values of `distribution` for every one of `sampling_points`
+/// With a seed the value depends only on the seed and the time of the point, so it doesn't change between evaluations
+fn random_values<T>(sampling_points: &[f64], seed: Option<u64>, distribution: impl Distribution<T>) -> Vec<T> {
+	return match seed {
+		Some(seed) => sampling_points.iter().map(|point| distribution.sample(&mut StdRng::seed_from_u64(derive_seed(seed, point.to_bits())))).collect(),
+		None => distribution.sample_iter(rand::thread_rng()).take(sampling_points.len()).collect(),
+	};
+}
+
+/// Generator for signals whose every value depends on the previous ones, seeded ones repeat the same sequence
+fn sequence_rng(seed: Option<u64>) -> StdRng {
+	return match seed {
+		Some(seed) => StdRng::seed_from_u64(seed),
+		None => StdRng::from_entropy(),
+	};
+}
+
+/// Result of arithmetic `operation` ("add", "subtract", "multiply" or "divide") on `a` and `b`, None for unknown operations
+pub fn apply_operation(operation: &str, a: f64, b: f64) -> Option<f64> {
+	return match operation {
+		"add" => Some(a + b),
+		"subtract" => Some(a - b),
+		"multiply" => Some(a * b),
+		"divide" => Some(a / b),
+		_ => None,
+	};
+}
+
+/// Feeds everything that determines values of `signal` into `state`: its type, parameters, stored data and operands
+pub fn fingerprint(signal: &dyn CalculableSignal, state: &mut impl Hasher) {
+	signal.get_type_name().hash(state);
+	for (name, value) in signal.get_parameters() {
+		name.hash(state);
+		value.to_bits().hash(state);
+	}
+	if let Some(samples) = signal.get_samples() {
+		samples.iter().for_each(|sample| sample.to_bits().hash(state));
+	}
+	signal.get_text().hash(state);
+	for operand in signal.get_operands() {
+		fingerprint(operand, state);
+	}
+}
+
+/// Checks `value` of parameter `name` against the range it makes sense in for every signal type having it
+pub fn validate_parameter(name: &str, value: f64) -> Result<(), String> {
+	if !value.is_finite() {
+		return Err(format!("Parameter {} has to be a finite number, got {}", name, value));
+	}
+	return match name {
+		"duration" if value < 0.0 => Err(format!("Duration can't be negative, got {}", value)),
+		"duty_cycle" | "probability" if !(0.0..=1.0).contains(&value) => Err(format!("Parameter {} has to be between 0 and 1, got {}", name, value)),
+		"sampling_frequency" | "period" | "step_duration" | "words_per_minute" if value <= 0.0 => Err(format!("Parameter {} has to be positive, got {}", name, value)),
+		_ => Ok(()),
+	};
+}
+
+/// Creates signal of kind `type_name` with default parameters, to be adjusted with `CalculableSignal::set_parameter`
+/// `samples` are only used by signals storing raw values, which take the interpolation method from `text`,
+/// `text` by signals generated from text and `operands` by signals combining other signals, which also take the operation from `text`
+pub fn create_signal(type_name: &str, samples: Vec<f64>, text: String, operands: Vec<Box<dyn CalculableSignal>>) -> Option<Box<dyn CalculableSignal>> {
+	return Some(match type_name {
+		"sine" => Box::new(SineSignal::new(1.0, 1.0, 0.0, 1.0, 0.0)),
+		"half_wave_rectified_sine" => Box::new(HalfWaveRectifiedSineSignal::new(1.0, 1.0, 0.0, 1.0, 0.0)),
+		"full_wave_rectified_sine" => Box::new(FullWaveRectifiedSineSignal::new(1.0, 1.0, 0.0, 1.0, 0.0)),
+		"uniform_noise" => Box::new(UniformNoise::new(1.0, 0.0, 1.0)),
+		"normal_noise" => Box::new(NormalNoise::new(1.0, 0.0, 1.0)),
+		"rectangular" => Box::new(RectangularSignal::new(1.0, 1.0, 0.0, 1.0, 0.5)),
+		"symmetric_rectangular" => Box::new(SymmetricRectangularSignal::new(1.0, 1.0, 0.0, 1.0, 0.5)),
+		"triangular" => Box::new(TriangularSignal::new(1.0, 1.0, 0.0, 1.0, 0.5)),
+		"unit_jump" => Box::new(UnitJump::new(0.0, 1.0, 0.0, 1.0)),
+		"unit_pulse" => Box::new(UnitPulse::new(0.0, 1.0, 0.0, 1.0)),
+		"unit_noise" => Box::new(UnitNoise::new(0.5, 1.0, 0.0, 1.0)),
+		"impulse_train" => Box::new(ImpulseTrain::new(0.1, 1.0, 0.0, 1.0)),
+		"damped_sine" => Box::new(DampedSineSignal::new(1.0, 1.0, 1.0, 0.0, 1.0, 0.0)),
+		"gaussian_pulse" => Box::new(GaussianPulse::new(0.5, 0.1, 1.0, 0.0, 1.0)),
+		"sinc_pulse" => Box::new(SincPulse::new(0.5, 10.0, 1.0, 0.0, 1.0)),
+		"ramp" => Box::new(RampSignal::new(1.0, 1.0, 0.0)),
+		"staircase" => Box::new(StaircaseSignal::new(0.1, 0.1, 1.0, 0.0)),
+		"fourier_square" => Box::new(FourierSquareSignal::new(1.0, 5.0, 1.0, 0.0, 1.0)),
+		"pink_noise" => Box::new(PinkNoise::new(1.0, 0.0, 1.0)),
+		"brownian_noise" => Box::new(BrownianNoise::new(1.0, 0.0, 1.0)),
+		"impulsive_noise" => Box::new(ImpulsiveNoise::new(0.05, 1.0, 1.0, 1.0, 0.0)),
+		// projects from before interpolation was configurable have no text
+		"sampled" => {
+			let interpolation = if text.is_empty() { "nearest".to_string() } else { text };
+			if !crate::resampling::is_method(&interpolation) {
+				return None;
+			}
+			Box::new(SampledSignal::new(1.0, 0.0, samples, interpolation))
+		}
+		"morse" => Box::new(MorseSignal::new(text, 20.0, 600.0, 0.0, 1.0)),
+		"expression" => Box::new(ExpressionSignal::new(text, 1.0, 0.0).ok()?),
+		"composite" => {
+			let [left, right]: [Box<dyn CalculableSignal>; 2] = operands.try_into().ok()?;
+			apply_operation(&text, 0.0, 1.0)?;
+			Box::new(CompositeSignal::new(text, left, right))
+		}
+		_ => return None,
+	});
 }
 
+#[derive(Clone)]
 pub struct SineSignal {
 	/// Frequency in Hz
 	signal_freq: f64,
@@ -22,16 +181,49 @@ pub struct SineSignal {
 
 impl CalculableSignal for SineSignal {
 	fn calculate_signal(&self, sampling_points: &[f64]) -> Vec<crate::CoordPair> {
+		#[cfg(feature = "simd")]
+		let values = crate::simd::sine_wave(sampling_points, self.signal_freq * TAU, self.phase_shift, self.amplitude);
+		#[cfg(not(feature = "simd"))]
+		let values = sampling_points.iter().map(|point| self.amplitude * (self.signal_freq * TAU * point + self.phase_shift).sin());
+		return sampling_points.iter().zip(values).map(|(point, value)| crate::CoordPair { x: *point, y: value }).collect();
+	}
+	fn calculate_values_f32(&self, sampling_points: &[f64]) -> Vec<f32> {
+		let amplitude = self.amplitude as f32;
 		return sampling_points.iter().map(|point| {
-			return crate::CoordPair {
-				x: *point,
-				y: self.amplitude * (self.signal_freq * TAU * point + self.phase_shift).sin()
-			};
+			// phase is reduced to a single turn in f64, in f32 it would lose precision over a long signal
+			let turn = (self.signal_freq * point + self.phase_shift / TAU).rem_euclid(1.0) as f32;
+			return amplitude * (std::f32::consts::TAU * turn).sin();
 		}).collect();
 	}
+	fn get_signal_start(&self) -> f64 {
+		return self.start_offset;
+	}
 	fn get_signal_end(&self) -> f64 {
 		return self.start_offset + self.duration;
 	}
+	fn get_parameters(&self) -> Vec<(&'static str, f64)> {
+		return vec![("signal_freq", self.signal_freq), ("duration", self.duration), ("start_offset", self.start_offset), ("amplitude", self.amplitude), ("phase_shift", self.phase_shift)];
+	}
+	fn set_parameter(&mut self, name: &str, value: f64) -> bool {
+		match name {
+			"signal_freq" => self.signal_freq = value,
+			"duration" => self.duration = value,
+			"start_offset" => self.start_offset = value,
+			"amplitude" => self.amplitude = value,
+			"phase_shift" => self.phase_shift = value,
+			_ => return false,
+		}
+		return true;
+	}
+	fn clone_box(&self) -> Box<dyn CalculableSignal> {
+		return Box::new(self.clone());
+	}
+	fn get_type_name(&self) -> &'static str {
+		return "sine";
+	}
+	fn get_period(&self) -> Option<f64> {
+		return Some(self.signal_freq.recip());
+	}
 }
 
 impl SineSignal {
@@ -46,6 +238,7 @@ impl SineSignal {
 	}
 }
 
+#[derive(Clone)]
 pub struct HalfWaveRectifiedSineSignal {
 	/// Sine signal source to rectify
 	inner_sine: SineSignal,
@@ -58,9 +251,30 @@ impl CalculableSignal for HalfWaveRectifiedSineSignal {
 			return sample;
 		}).collect();
 	}
+	fn calculate_values_f32(&self, sampling_points: &[f64]) -> Vec<f32> {
+		return self.inner_sine.calculate_values_f32(sampling_points).into_iter().map(|value| value.clamp(0.0, f32::MAX)).collect();
+	}
+	fn get_signal_start(&self) -> f64 {
+		return self.inner_sine.start_offset;
+	}
 	fn get_signal_end(&self) -> f64 {
 		return self.inner_sine.start_offset + self.inner_sine.duration;
 	}
+	fn get_parameters(&self) -> Vec<(&'static str, f64)> {
+		return self.inner_sine.get_parameters();
+	}
+	fn set_parameter(&mut self, name: &str, value: f64) -> bool {
+		return self.inner_sine.set_parameter(name, value);
+	}
+	fn clone_box(&self) -> Box<dyn CalculableSignal> {
+		return Box::new(self.clone());
+	}
+	fn get_type_name(&self) -> &'static str {
+		return "half_wave_rectified_sine";
+	}
+	fn get_period(&self) -> Option<f64> {
+		return Some(self.inner_sine.signal_freq.recip());
+	}
 }
 
 impl HalfWaveRectifiedSineSignal {
@@ -77,6 +291,7 @@ impl HalfWaveRectifiedSineSignal {
 	}
 }
 
+#[derive(Clone)]
 pub struct FullWaveRectifiedSineSignal {
 	/// Sine signal source to rectify
 	inner_sine: SineSignal,
@@ -89,9 +304,30 @@ impl CalculableSignal for FullWaveRectifiedSineSignal {
 			return sample;
 		}).collect();
 	}
+	fn calculate_values_f32(&self, sampling_points: &[f64]) -> Vec<f32> {
+		return self.inner_sine.calculate_values_f32(sampling_points).into_iter().map(f32::abs).collect();
+	}
+	fn get_signal_start(&self) -> f64 {
+		return self.inner_sine.start_offset;
+	}
 	fn get_signal_end(&self) -> f64 {
 		return self.inner_sine.start_offset + self.inner_sine.duration;
 	}
+	fn get_parameters(&self) -> Vec<(&'static str, f64)> {
+		return self.inner_sine.get_parameters();
+	}
+	fn set_parameter(&mut self, name: &str, value: f64) -> bool {
+		return self.inner_sine.set_parameter(name, value);
+	}
+	fn clone_box(&self) -> Box<dyn CalculableSignal> {
+		return Box::new(self.clone());
+	}
+	fn get_type_name(&self) -> &'static str {
+		return "full_wave_rectified_sine";
+	}
+	fn get_period(&self) -> Option<f64> {
+		return Some(self.inner_sine.signal_freq.recip());
+	}
 }
 
 impl FullWaveRectifiedSineSignal {
@@ -108,6 +344,7 @@ impl FullWaveRectifiedSineSignal {
 	}
 }
 
+#[derive(Clone)]
 pub struct UniformNoise {
 	/// Duration in s
 	duration: f64,
@@ -115,20 +352,49 @@ pub struct UniformNoise {
 	start_offset: f64,
 	/// Dimensionless amplitude
 	amplitude: f64,
+	/// Seed of random values, None draws new values on every evaluation
+	seed: Option<u64>,
 }
 
 impl CalculableSignal for UniformNoise {
 	fn calculate_signal(&self, sampling_points: &[f64]) -> Vec<crate::CoordPair> {
-		return sampling_points.iter().zip(rand::distributions::Uniform::new(-self.amplitude, self.amplitude).sample_iter(rand::thread_rng())).map(|(point, value)| {
+		return sampling_points.iter().zip(random_values(sampling_points, self.seed, rand::distributions::Uniform::new(-self.amplitude, self.amplitude))).map(|(point, value)| {
 			return crate::CoordPair {
 				x: *point,
 				y: value
 			};
 		}).collect();
 	}
+	fn get_signal_start(&self) -> f64 {
+		return self.start_offset;
+	}
 	fn get_signal_end(&self) -> f64 {
 		return self.start_offset + self.duration;
 	}
+	fn get_parameters(&self) -> Vec<(&'static str, f64)> {
+		return vec![("duration", self.duration), ("start_offset", self.start_offset), ("amplitude", self.amplitude)];
+	}
+	fn set_parameter(&mut self, name: &str, value: f64) -> bool {
+		match name {
+			"duration" => self.duration = value,
+			"start_offset" => self.start_offset = value,
+			"amplitude" => self.amplitude = value,
+			_ => return false,
+		}
+		return true;
+	}
+	fn clone_box(&self) -> Box<dyn CalculableSignal> {
+		return Box::new(self.clone());
+	}
+	fn get_type_name(&self) -> &'static str {
+		return "uniform_noise";
+	}
+	fn with_seed(&self, seed: u64) -> Option<Box<dyn CalculableSignal>> {
+		return Some(Box::new(Self {
+			seed: Some(seed),
+			..self.clone()
+		}));
+	}
 }
 
 impl UniformNoise {
@@ -137,10 +403,12 @@ impl UniformNoise {
 			duration,
 			start_offset,
 			amplitude,
+			seed: None,
 		};
 	}
 }
 
+#[derive(Clone)]
 pub struct NormalNoise {
 	/// Duration in s
 	duration: f64,
@@ -148,20 +416,49 @@ pub struct NormalNoise {
 	start_offset: f64,
 	/// Dimensionless amplitude
 	amplitude: f64,
+	/// Seed of random values, None draws new values on every evaluation
+	seed: Option<u64>,
 }
 
 impl CalculableSignal for NormalNoise {
 	fn calculate_signal(&self, sampling_points: &[f64]) -> Vec<crate::CoordPair> {
-		return sampling_points.iter().zip(rand::thread_rng().sample_iter(rand_distr::StandardNormal)).map(|(point, value): (_, f64)| {
+		return sampling_points.iter().zip(random_values(sampling_points, self.seed, rand_distr::StandardNormal)).map(|(point, value): (_, f64)| {
 			return crate::CoordPair {
 				x: *point,
 				y: value * self.amplitude
 			};
 		}).collect();
 	}
+	fn get_signal_start(&self) -> f64 {
+		return self.start_offset;
+	}
 	fn get_signal_end(&self) -> f64 {
 		return self.start_offset + self.duration;
 	}
+	fn get_parameters(&self) -> Vec<(&'static str, f64)> {
+		return vec![("duration", self.duration), ("start_offset", self.start_offset), ("amplitude", self.amplitude)];
+	}
+	fn set_parameter(&mut self, name: &str, value: f64) -> bool {
+		match name {
+			"duration" => self.duration = value,
+			"start_offset" => self.start_offset = value,
+			"amplitude" => self.amplitude = value,
+			_ => return false,
+		}
+		return true;
+	}
+	fn clone_box(&self) -> Box<dyn CalculableSignal> {
+		return Box::new(self.clone());
+	}
+	fn get_type_name(&self) -> &'static str {
+		return "normal_noise";
+	}
+	fn with_seed(&self, seed: u64) -> Option<Box<dyn CalculableSignal>> {
+		return Some(Box::new(Self {
+			seed: Some(seed),
+			..self.clone()
+		}));
+	}
 }
 
 impl NormalNoise {
@@ -170,10 +467,12 @@ impl NormalNoise {
 			duration,
 			start_offset,
 			amplitude,
+			seed: None,
 		};
 	}
 }
 
+#[derive(Clone)]
 pub struct RectangularSignal {
 	/// Sine signal source to rectify
 	inner_signal: SymmetricRectangularSignal,
@@ -186,9 +485,27 @@ impl CalculableSignal for RectangularSignal {
 			return point;
 		}).collect();
 	}
+	fn get_signal_start(&self) -> f64 {
+		return self.inner_signal.start_offset;
+	}
 	fn get_signal_end(&self) -> f64 {
 		return self.inner_signal.start_offset + self.inner_signal.duration;
 	}
+	fn get_parameters(&self) -> Vec<(&'static str, f64)> {
+		return self.inner_signal.get_parameters();
+	}
+	fn set_parameter(&mut self, name: &str, value: f64) -> bool {
+		return self.inner_signal.set_parameter(name, value);
+	}
+	fn clone_box(&self) -> Box<dyn CalculableSignal> {
+		return Box::new(self.clone());
+	}
+	fn get_type_name(&self) -> &'static str {
+		return "rectangular";
+	}
+	fn get_period(&self) -> Option<f64> {
+		return Some(self.inner_signal.signal_freq.recip());
+	}
 }
 
 impl RectangularSignal {
@@ -205,6 +522,7 @@ impl RectangularSignal {
 	}
 }
 
+#[derive(Clone)]
 pub struct SymmetricRectangularSignal {
 	/// Frequency in Hz
 	signal_freq: f64,
@@ -222,6 +540,9 @@ impl CalculableSignal for SymmetricRectangularSignal {
 	fn calculate_signal(&self, sampling_points: &[f64]) -> Vec<crate::CoordPair> {
 		let function_period = 1.0 / self.signal_freq;
 		let flip_point_within_period = function_period * self.duty_cycle;
+		#[cfg(feature = "simd")]
+		return sampling_points.iter().zip(crate::simd::rectangular_wave(sampling_points, function_period, flip_point_within_period, self.amplitude)).map(|(point, value)| crate::CoordPair { x: *point, y: value }).collect();
+		#[cfg(not(feature = "simd"))]
 		return sampling_points.iter().map(|point| {
 			let offset_within_period = point % function_period;
 			return crate::CoordPair {
@@ -230,9 +551,35 @@ impl CalculableSignal for SymmetricRectangularSignal {
 			};
 		}).collect();
 	}
+	fn get_signal_start(&self) -> f64 {
+		return self.start_offset;
+	}
 	fn get_signal_end(&self) -> f64 {
 		return self.start_offset + self.duration;
 	}
+	fn get_parameters(&self) -> Vec<(&'static str, f64)> {
+		return vec![("signal_freq", self.signal_freq), ("duration", self.duration), ("start_offset", self.start_offset), ("amplitude", self.amplitude), ("duty_cycle", self.duty_cycle)];
+	}
+	fn set_parameter(&mut self, name: &str, value: f64) -> bool {
+		match name {
+			"signal_freq" => self.signal_freq = value,
+			"duration" => self.duration = value,
+			"start_offset" => self.start_offset = value,
+			"amplitude" => self.amplitude = value,
+			"duty_cycle" => self.duty_cycle = value,
+			_ => return false,
+		}
+		return true;
+	}
+	fn clone_box(&self) -> Box<dyn CalculableSignal> {
+		return Box::new(self.clone());
+	}
+	fn get_type_name(&self) -> &'static str {
+		return "symmetric_rectangular";
+	}
+	fn get_period(&self) -> Option<f64> {
+		return Some(self.signal_freq.recip());
+	}
 }
 
 impl SymmetricRectangularSignal {
@@ -247,6 +594,7 @@ impl SymmetricRectangularSignal {
 	}
 }
 
+#[derive(Clone)]
 pub struct TriangularSignal {
 	/// Frequency in Hz
 	signal_freq: f64,
@@ -264,6 +612,9 @@ impl CalculableSignal for TriangularSignal {
 	fn calculate_signal(&self, sampling_points: &[f64]) -> Vec<crate::CoordPair> {
 		let function_period = self.signal_freq.recip();
 		let flip_point_within_period = function_period * self.duty_cycle;
+		#[cfg(feature = "simd")]
+		return sampling_points.iter().zip(crate::simd::triangular_wave(sampling_points, function_period, flip_point_within_period, self.amplitude)).map(|(point, value)| crate::CoordPair { x: *point, y: value }).collect();
+		#[cfg(not(feature = "simd"))]
 		return sampling_points.iter().map(|point| {
 			let offset_within_period = point % function_period;
 			// triangle wave has two parts - before flip point and after flip point. Flip point does not need to be in the middle
@@ -276,9 +627,44 @@ impl CalculableSignal for TriangularSignal {
 			};
 		}).collect();
 	}
+	fn calculate_values_f32(&self, sampling_points: &[f64]) -> Vec<f32> {
+		let (amplitude, flip_point) = (self.amplitude as f32, self.duty_cycle as f32);
+		return sampling_points.iter().map(|point| {
+			// position within the period is found in f64 like the phase of the sine, the shape is computed in f32
+			let position = ((point % self.signal_freq.recip()) * self.signal_freq) as f32;
+			let part_of_wave_side = if position > flip_point { (position - flip_point) / (1.0 - flip_point) } else { 1.0 - position / flip_point };
+			return part_of_wave_side * amplitude;
+		}).collect();
+	}
+	fn get_signal_start(&self) -> f64 {
+		return self.start_offset;
+	}
 	fn get_signal_end(&self) -> f64 {
 		return self.start_offset + self.duration;
 	}
+	fn get_parameters(&self) -> Vec<(&'static str, f64)> {
+		return vec![("signal_freq", self.signal_freq), ("duration", self.duration), ("start_offset", self.start_offset), ("amplitude", self.amplitude), ("duty_cycle", self.duty_cycle)];
+	}
+	fn set_parameter(&mut self, name: &str, value: f64) -> bool {
+		match name {
+			"signal_freq" => self.signal_freq = value,
+			"duration" => self.duration = value,
+			"start_offset" => self.start_offset = value,
+			"amplitude" => self.amplitude = value,
+			"duty_cycle" => self.duty_cycle = value,
+			_ => return false,
+		}
+		return true;
+	}
+	fn clone_box(&self) -> Box<dyn CalculableSignal> {
+		return Box::new(self.clone());
+	}
+	fn get_type_name(&self) -> &'static str {
+		return "triangular";
+	}
+	fn get_period(&self) -> Option<f64> {
+		return Some(self.signal_freq.recip());
+	}
 }
 
 impl TriangularSignal {
@@ -293,6 +679,7 @@ impl TriangularSignal {
 	}
 }
 
+#[derive(Clone)]
 pub struct UnitJump {
 	/// Time when signal changes from 0 to 1, in seconds relative to local starting point
 	flip_offset: f64,
@@ -315,9 +702,31 @@ impl CalculableSignal for UnitJump {
 			};
 		}).collect();
 	}
+	fn get_signal_start(&self) -> f64 {
+		return self.start_offset;
+	}
 	fn get_signal_end(&self) -> f64 {
 		return self.start_offset + self.duration;
 	}
+	fn get_parameters(&self) -> Vec<(&'static str, f64)> {
+		return vec![("flip_offset", self.flip_offset), ("duration", self.duration), ("start_offset", self.start_offset), ("amplitude", self.amplitude)];
+	}
+	fn set_parameter(&mut self, name: &str, value: f64) -> bool {
+		match name {
+			"flip_offset" => self.flip_offset = value,
+			"duration" => self.duration = value,
+			"start_offset" => self.start_offset = value,
+			"amplitude" => self.amplitude = value,
+			_ => return false,
+		}
+		return true;
+	}
+	fn clone_box(&self) -> Box<dyn CalculableSignal> {
+		return Box::new(self.clone());
+	}
+	fn get_type_name(&self) -> &'static str {
+		return "unit_jump";
+	}
 }
 
 impl UnitJump {
@@ -331,6 +740,7 @@ impl UnitJump {
 	}
 }
 
+#[derive(Clone)]
 pub struct UnitPulse {
 	/// Time when signal changes from 0 to 1, in seconds relative to local starting point
 	/// Will snap to the closest measurement point
@@ -366,9 +776,35 @@ impl CalculableSignal for UnitPulse {
 			};
 		}).collect();
 	}
+	// the pulse goes to the point nearest to the flip point among all given ones
+	fn is_pointwise(&self) -> bool {
+		return false;
+	}
+	fn get_signal_start(&self) -> f64 {
+		return self.start_offset;
+	}
 	fn get_signal_end(&self) -> f64 {
 		return self.start_offset + self.duration;
 	}
+	fn get_parameters(&self) -> Vec<(&'static str, f64)> {
+		return vec![("time_offset", self.time_offset), ("duration", self.duration), ("start_offset", self.start_offset), ("amplitude", self.amplitude)];
+	}
+	fn set_parameter(&mut self, name: &str, value: f64) -> bool {
+		match name {
+			"time_offset" => self.time_offset = value,
+			"duration" => self.duration = value,
+			"start_offset" => self.start_offset = value,
+			"amplitude" => self.amplitude = value,
+			_ => return false,
+		}
+		return true;
+	}
+	fn clone_box(&self) -> Box<dyn CalculableSignal> {
+		return Box::new(self.clone());
+	}
+	fn get_type_name(&self) -> &'static str {
+		return "unit_pulse";
+	}
 }
 
 impl UnitPulse {
@@ -382,6 +818,7 @@ impl UnitPulse {
 	}
 }
 
+#[derive(Clone)]
 pub struct UnitNoise {
 	/// Probability for signal to be amplitude. Between 0 and 1
 	probability: f64,
@@ -391,20 +828,50 @@ pub struct UnitNoise {
 	start_offset: f64,
 	/// Dimensionless amplitude
 	amplitude: f64,
+	/// Seed of random values, None draws new values on every evaluation
+	seed: Option<u64>,
 }
 
 impl CalculableSignal for UnitNoise {
 	fn calculate_signal(&self, sampling_points: &[f64]) -> Vec<crate::CoordPair> {
-		return sampling_points.iter().zip(rand_distr::Bernoulli::new(self.probability).unwrap().sample_iter(rand::thread_rng())).map(|(point, value)| {
+		return sampling_points.iter().zip(random_values(sampling_points, self.seed, rand_distr::Bernoulli::new(self.probability).unwrap())).map(|(point, value)| {
 			return crate::CoordPair {
 				x: *point,
 				y: if value { self.amplitude } else { 0.0 },
 			};
 		}).collect();
 	}
+	fn get_signal_start(&self) -> f64 {
+		return self.start_offset;
+	}
 	fn get_signal_end(&self) -> f64 {
 		return self.start_offset + self.duration;
 	}
+	fn get_parameters(&self) -> Vec<(&'static str, f64)> {
+		return vec![("probability", self.probability), ("duration", self.duration), ("start_offset", self.start_offset), ("amplitude", self.amplitude)];
+	}
+	fn set_parameter(&mut self, name: &str, value: f64) -> bool {
+		match name {
+			"probability" => self.probability = value,
+			"duration" => self.duration = value,
+			"start_offset" => self.start_offset = value,
+			"amplitude" => self.amplitude = value,
+			_ => return false,
+		}
+		return true;
+	}
+	fn clone_box(&self) -> Box<dyn CalculableSignal> {
+		return Box::new(self.clone());
+	}
+	fn get_type_name(&self) -> &'static str {
+		return "unit_noise";
+	}
+	fn with_seed(&self, seed: u64) -> Option<Box<dyn CalculableSignal>> {
+		return Some(Box::new(Self {
+			seed: Some(seed),
+			..self.clone()
+		}));
+	}
 }
 
 impl UnitNoise {
@@ -414,6 +881,993 @@ impl UnitNoise {
 			duration,
 			start_offset,
 			amplitude,
+			seed: None,
+		};
+	}
+}
+
+#[derive(Clone)]
+pub struct ImpulseTrain {
+	/// Time between pulses in s, the first pulse is at the start of the signal
+	/// Every pulse snaps to the closest measurement point
+	period: f64,
+	/// Duration in s
+	duration: f64,
+	/// Starting time in s relative to global starting point
+	start_offset: f64,
+	/// Dimensionless amplitude
+	amplitude: f64,
+}
+
+impl CalculableSignal for ImpulseTrain {
+	fn calculate_signal(&self, sampling_points: &[f64]) -> Vec<crate::CoordPair> {
+		let nearest_pulse = |point: f64| self.start_offset + ((point - self.start_offset) / self.period).round() * self.period;
+		return sampling_points.iter().enumerate().map(|(index, point)| {
+			// a point carries the pulse when its neighbours are further from it, ties go to the earlier point
+			let pulse_time = nearest_pulse(*point);
+			let distance = (point - pulse_time).abs();
+			// at the edges the missing neighbour is assumed one sampling period away
+			let spacing = if index + 1 < sampling_points.len() { sampling_points[index + 1] - point } else if index > 0 { point - sampling_points[index - 1] } else { f64::INFINITY };
+			let is_closest = (if index == 0 { distance < 0.5 * spacing } else { (sampling_points[index - 1] - pulse_time).abs() > distance })
+				&& (if index + 1 == sampling_points.len() { distance <= 0.5 * spacing } else { (sampling_points[index + 1] - pulse_time).abs() >= distance });
+			return crate::CoordPair {
+				x: *point,
+				y: if is_closest { self.amplitude } else { 0.0 },
+			};
+		}).collect();
+	}
+	fn get_signal_start(&self) -> f64 {
+		return self.start_offset;
+	}
+	fn get_signal_end(&self) -> f64 {
+		return self.start_offset + self.duration;
+	}
+	fn get_parameters(&self) -> Vec<(&'static str, f64)> {
+		return vec![("period", self.period), ("duration", self.duration), ("start_offset", self.start_offset), ("amplitude", self.amplitude)];
+	}
+	fn set_parameter(&mut self, name: &str, value: f64) -> bool {
+		match name {
+			"period" => self.period = value,
+			"duration" => self.duration = value,
+			"start_offset" => self.start_offset = value,
+			"amplitude" => self.amplitude = value,
+			_ => return false,
+		}
+		return true;
+	}
+	fn clone_box(&self) -> Box<dyn CalculableSignal> {
+		return Box::new(self.clone());
+	}
+	fn get_type_name(&self) -> &'static str {
+		return "impulse_train";
+	}
+	fn get_period(&self) -> Option<f64> {
+		return Some(self.period);
+	}
+	fn is_pointwise(&self) -> bool {
+		return false;
+	}
+}
+
+impl ImpulseTrain {
+	pub fn new(period: f64, duration: f64, start_offset: f64, amplitude: f64) -> Self {
+		return Self {
+			period,
+			duration,
+			start_offset,
+			amplitude,
+		};
+	}
+}
+
+/// Sine decaying exponentially from the start of the signal
+#[derive(Clone)]
+pub struct DampedSineSignal {
+	/// Frequency in Hz
+	signal_freq: f64,
+	/// Time in s after which the envelope falls to 1/e
+	decay_constant: f64,
+	/// Duration in s
+	duration: f64,
+	/// Starting time in s relative to global starting point
+	start_offset: f64,
+	/// Dimensionless amplitude
+	amplitude: f64,
+	/// Phase shift in radians
+	phase_shift: f64,
+}
+
+impl CalculableSignal for DampedSineSignal {
+	fn calculate_signal(&self, sampling_points: &[f64]) -> Vec<crate::CoordPair> {
+		return sampling_points.iter().map(|point| {
+			let envelope = (-(point - self.start_offset) / self.decay_constant).exp();
+			return crate::CoordPair {
+				x: *point,
+				y: self.amplitude * envelope * (self.signal_freq * TAU * point + self.phase_shift).sin(),
+			};
+		}).collect();
+	}
+	fn get_signal_start(&self) -> f64 {
+		return self.start_offset;
+	}
+	fn get_signal_end(&self) -> f64 {
+		return self.start_offset + self.duration;
+	}
+	fn get_parameters(&self) -> Vec<(&'static str, f64)> {
+		return vec![("signal_freq", self.signal_freq), ("decay_constant", self.decay_constant), ("duration", self.duration), ("start_offset", self.start_offset), ("amplitude", self.amplitude), ("phase_shift", self.phase_shift)];
+	}
+	fn set_parameter(&mut self, name: &str, value: f64) -> bool {
+		match name {
+			"signal_freq" => self.signal_freq = value,
+			"decay_constant" => self.decay_constant = value,
+			"duration" => self.duration = value,
+			"start_offset" => self.start_offset = value,
+			"amplitude" => self.amplitude = value,
+			"phase_shift" => self.phase_shift = value,
+			_ => return false,
+		}
+		return true;
+	}
+	fn clone_box(&self) -> Box<dyn CalculableSignal> {
+		return Box::new(self.clone());
+	}
+	fn get_type_name(&self) -> &'static str {
+		return "damped_sine";
+	}
+}
+
+impl DampedSineSignal {
+	pub fn new(signal_freq: f64, decay_constant: f64, duration: f64, start_offset: f64, amplitude: f64, phase_shift: f64) -> Self {
+		return Self {
+			signal_freq,
+			decay_constant,
+			duration,
+			start_offset,
+			amplitude,
+			phase_shift,
+		};
+	}
+}
+
+#[derive(Clone)]
+pub struct GaussianPulse {
+	/// Time of the peak in s relative to local starting point
+	center: f64,
+	/// Standard deviation of the bell in s
+	width: f64,
+	/// Duration in s
+	duration: f64,
+	/// Starting time in s relative to global starting point
+	start_offset: f64,
+	/// Dimensionless amplitude
+	amplitude: f64,
+}
+
+impl CalculableSignal for GaussianPulse {
+	fn calculate_signal(&self, sampling_points: &[f64]) -> Vec<crate::CoordPair> {
+		return sampling_points.iter().map(|point| {
+			let distance = (point - self.start_offset - self.center) / self.width;
+			return crate::CoordPair {
+				x: *point,
+				y: self.amplitude * (-0.5 * distance * distance).exp(),
+			};
+		}).collect();
+	}
+	fn get_signal_start(&self) -> f64 {
+		return self.start_offset;
+	}
+	fn get_signal_end(&self) -> f64 {
+		return self.start_offset + self.duration;
+	}
+	fn get_parameters(&self) -> Vec<(&'static str, f64)> {
+		return vec![("center", self.center), ("width", self.width), ("duration", self.duration), ("start_offset", self.start_offset), ("amplitude", self.amplitude)];
+	}
+	fn set_parameter(&mut self, name: &str, value: f64) -> bool {
+		match name {
+			"center" => self.center = value,
+			"width" => self.width = value,
+			"duration" => self.duration = value,
+			"start_offset" => self.start_offset = value,
+			"amplitude" => self.amplitude = value,
+			_ => return false,
+		}
+		return true;
+	}
+	fn clone_box(&self) -> Box<dyn CalculableSignal> {
+		return Box::new(self.clone());
+	}
+	fn get_type_name(&self) -> &'static str {
+		return "gaussian_pulse";
+	}
+}
+
+impl GaussianPulse {
+	pub fn new(center: f64, width: f64, duration: f64, start_offset: f64, amplitude: f64) -> Self {
+		return Self {
+			center,
+			width,
+			duration,
+			start_offset,
+			amplitude,
+		};
+	}
+}
+
+#[derive(Clone)]
+pub struct SincPulse {
+	/// Time of the peak in s relative to local starting point
+	center: f64,
+	/// Highest frequency in Hz of the flat spectrum, zero crossings are 1 / (2 * `bandwidth`) s apart
+	bandwidth: f64,
+	/// Duration in s
+	duration: f64,
+	/// Starting time in s relative to global starting point
+	start_offset: f64,
+	/// Dimensionless amplitude
+	amplitude: f64,
+}
+
+impl CalculableSignal for SincPulse {
+	fn calculate_signal(&self, sampling_points: &[f64]) -> Vec<crate::CoordPair> {
+		return sampling_points.iter().map(|point| {
+			let phase = TAU * self.bandwidth * (point - self.start_offset - self.center);
+			return crate::CoordPair {
+				x: *point,
+				y: if phase == 0.0 { self.amplitude } else { self.amplitude * phase.sin() / phase },
+			};
+		}).collect();
+	}
+	fn get_signal_start(&self) -> f64 {
+		return self.start_offset;
+	}
+	fn get_signal_end(&self) -> f64 {
+		return self.start_offset + self.duration;
+	}
+	fn get_parameters(&self) -> Vec<(&'static str, f64)> {
+		return vec![("center", self.center), ("bandwidth", self.bandwidth), ("duration", self.duration), ("start_offset", self.start_offset), ("amplitude", self.amplitude)];
+	}
+	fn set_parameter(&mut self, name: &str, value: f64) -> bool {
+		match name {
+			"center" => self.center = value,
+			"bandwidth" => self.bandwidth = value,
+			"duration" => self.duration = value,
+			"start_offset" => self.start_offset = value,
+			"amplitude" => self.amplitude = value,
+			_ => return false,
+		}
+		return true;
+	}
+	fn clone_box(&self) -> Box<dyn CalculableSignal> {
+		return Box::new(self.clone());
+	}
+	fn get_type_name(&self) -> &'static str {
+		return "sinc_pulse";
+	}
+}
+
+impl SincPulse {
+	pub fn new(center: f64, bandwidth: f64, duration: f64, start_offset: f64, amplitude: f64) -> Self {
+		return Self {
+			center,
+			bandwidth,
+			duration,
+			start_offset,
+			amplitude,
+		};
+	}
+}
+
+#[derive(Clone)]
+pub struct RampSignal {
+	/// Change of value per second, starting from 0 at the start of the signal
+	rate: f64,
+	/// Duration in s
+	duration: f64,
+	/// Starting time in s relative to global starting point
+	start_offset: f64,
+}
+
+impl CalculableSignal for RampSignal {
+	fn calculate_signal(&self, sampling_points: &[f64]) -> Vec<crate::CoordPair> {
+		return sampling_points.iter().map(|point| {
+			return crate::CoordPair {
+				x: *point,
+				y: self.rate * (point - self.start_offset),
+			};
+		}).collect();
+	}
+	fn get_signal_start(&self) -> f64 {
+		return self.start_offset;
+	}
+	fn get_signal_end(&self) -> f64 {
+		return self.start_offset + self.duration;
+	}
+	fn get_parameters(&self) -> Vec<(&'static str, f64)> {
+		return vec![("rate", self.rate), ("duration", self.duration), ("start_offset", self.start_offset)];
+	}
+	fn set_parameter(&mut self, name: &str, value: f64) -> bool {
+		match name {
+			"rate" => self.rate = value,
+			"duration" => self.duration = value,
+			"start_offset" => self.start_offset = value,
+			_ => return false,
+		}
+		return true;
+	}
+	fn clone_box(&self) -> Box<dyn CalculableSignal> {
+		return Box::new(self.clone());
+	}
+	fn get_type_name(&self) -> &'static str {
+		return "ramp";
+	}
+}
+
+impl RampSignal {
+	pub fn new(rate: f64, duration: f64, start_offset: f64) -> Self {
+		return Self {
+			rate,
+			duration,
+			start_offset,
+		};
+	}
+}
+
+#[derive(Clone)]
+pub struct StaircaseSignal {
+	/// Change of value at every step, the first step starts at 0
+	step_height: f64,
+	/// Time in s between consecutive steps
+	step_duration: f64,
+	/// Duration in s
+	duration: f64,
+	/// Starting time in s relative to global starting point
+	start_offset: f64,
+}
+
+impl CalculableSignal for StaircaseSignal {
+	fn calculate_signal(&self, sampling_points: &[f64]) -> Vec<crate::CoordPair> {
+		return sampling_points.iter().map(|point| {
+			return crate::CoordPair {
+				x: *point,
+				y: self.step_height * ((point - self.start_offset) / self.step_duration).floor(),
+			};
+		}).collect();
+	}
+	fn get_signal_start(&self) -> f64 {
+		return self.start_offset;
+	}
+	fn get_signal_end(&self) -> f64 {
+		return self.start_offset + self.duration;
+	}
+	fn get_parameters(&self) -> Vec<(&'static str, f64)> {
+		return vec![("step_height", self.step_height), ("step_duration", self.step_duration), ("duration", self.duration), ("start_offset", self.start_offset)];
+	}
+	fn set_parameter(&mut self, name: &str, value: f64) -> bool {
+		match name {
+			"step_height" => self.step_height = value,
+			"step_duration" => self.step_duration = value,
+			"duration" => self.duration = value,
+			"start_offset" => self.start_offset = value,
+			_ => return false,
+		}
+		return true;
+	}
+	fn clone_box(&self) -> Box<dyn CalculableSignal> {
+		return Box::new(self.clone());
+	}
+	fn get_type_name(&self) -> &'static str {
+		return "staircase";
+	}
+}
+
+impl StaircaseSignal {
+	pub fn new(step_height: f64, step_duration: f64, duration: f64, start_offset: f64) -> Self {
+		return Self {
+			step_height,
+			step_duration,
+			duration,
+			start_offset,
+		};
+	}
+}
+
+/// Square wave approximated by its Fourier series truncated after `harmonic_count` odd harmonics
+#[derive(Clone)]
+pub struct FourierSquareSignal {
+	/// Frequency in Hz
+	signal_freq: f64,
+	/// Number of odd harmonics summed, rounded to a whole number
+	harmonic_count: f64,
+	/// Duration in s
+	duration: f64,
+	/// Starting time in s relative to global starting point
+	start_offset: f64,
+	/// Dimensionless amplitude
+	amplitude: f64,
+}
+
+impl CalculableSignal for FourierSquareSignal {
+	fn calculate_signal(&self, sampling_points: &[f64]) -> Vec<crate::CoordPair> {
+		let harmonic_count = self.harmonic_count.round().max(0.0) as usize;
+		return sampling_points.iter().map(|point| {
+			let sum: f64 = (0..harmonic_count).map(|index| {
+				let harmonic = (2 * index + 1) as f64;
+				return (harmonic * self.signal_freq * TAU * point).sin() / harmonic;
+			}).sum();
+			return crate::CoordPair {
+				x: *point,
+				y: self.amplitude * 4.0 / std::f64::consts::PI * sum,
+			};
+		}).collect();
+	}
+	fn get_signal_start(&self) -> f64 {
+		return self.start_offset;
+	}
+	fn get_signal_end(&self) -> f64 {
+		return self.start_offset + self.duration;
+	}
+	fn get_parameters(&self) -> Vec<(&'static str, f64)> {
+		return vec![("signal_freq", self.signal_freq), ("harmonic_count", self.harmonic_count), ("duration", self.duration), ("start_offset", self.start_offset), ("amplitude", self.amplitude)];
+	}
+	fn set_parameter(&mut self, name: &str, value: f64) -> bool {
+		match name {
+			"signal_freq" => self.signal_freq = value,
+			"harmonic_count" => self.harmonic_count = value,
+			"duration" => self.duration = value,
+			"start_offset" => self.start_offset = value,
+			"amplitude" => self.amplitude = value,
+			_ => return false,
+		}
+		return true;
+	}
+	fn clone_box(&self) -> Box<dyn CalculableSignal> {
+		return Box::new(self.clone());
+	}
+	fn get_type_name(&self) -> &'static str {
+		return "fourier_square";
+	}
+	fn get_period(&self) -> Option<f64> {
+		return Some(self.signal_freq.recip());
+	}
+}
+
+impl FourierSquareSignal {
+	pub fn new(signal_freq: f64, harmonic_count: f64, duration: f64, start_offset: f64, amplitude: f64) -> Self {
+		return Self {
+			signal_freq,
+			harmonic_count,
+			duration,
+			start_offset,
+			amplitude,
+		};
+	}
+}
+
+/// Noise with power falling by 3 dB per octave
+#[derive(Clone)]
+pub struct PinkNoise {
+	/// Duration in s
+	duration: f64,
+	/// Starting time in s relative to global starting point
+	start_offset: f64,
+	/// Dimensionless amplitude, values stay between -amplitude and amplitude
+	amplitude: f64,
+	/// Seed of random values, None draws new values on every evaluation
+	seed: Option<u64>,
+}
+
+impl CalculableSignal for PinkNoise {
+	fn calculate_signal(&self, sampling_points: &[f64]) -> Vec<crate::CoordPair> {
+		// Voss-McCartney - row k is redrawn every 2^k samples, so slower rows add power at lower frequencies
+		let mut rng = sequence_rng(self.seed);
+		let uniform = rand::distributions::Uniform::new_inclusive(-1.0, 1.0);
+		let mut rows: Vec<f64> = (0..PINK_NOISE_ROWS).map(|_| uniform.sample(&mut rng)).collect();
+		return sampling_points.iter().enumerate().map(|(index, point)| {
+			let row = (index + 1).trailing_zeros() as usize;
+			if row < PINK_NOISE_ROWS {
+				rows[row] = uniform.sample(&mut rng);
+			}
+			let sum = rows.iter().sum::<f64>() + uniform.sample(&mut rng);
+			return crate::CoordPair {
+				x: *point,
+				y: self.amplitude * sum / (PINK_NOISE_ROWS + 1) as f64,
+			};
+		}).collect();
+	}
+	fn get_signal_start(&self) -> f64 {
+		return self.start_offset;
+	}
+	fn get_signal_end(&self) -> f64 {
+		return self.start_offset + self.duration;
+	}
+	fn get_parameters(&self) -> Vec<(&'static str, f64)> {
+		return vec![("duration", self.duration), ("start_offset", self.start_offset), ("amplitude", self.amplitude)];
+	}
+	fn set_parameter(&mut self, name: &str, value: f64) -> bool {
+		match name {
+			"duration" => self.duration = value,
+			"start_offset" => self.start_offset = value,
+			"amplitude" => self.amplitude = value,
+			_ => return false,
+		}
+		return true;
+	}
+	fn clone_box(&self) -> Box<dyn CalculableSignal> {
+		return Box::new(self.clone());
+	}
+	fn get_type_name(&self) -> &'static str {
+		return "pink_noise";
+	}
+	fn with_seed(&self, seed: u64) -> Option<Box<dyn CalculableSignal>> {
+		return Some(Box::new(Self {
+			seed: Some(seed),
+			..self.clone()
+		}));
+	}
+	fn is_pointwise(&self) -> bool {
+		return false;
+	}
+}
+
+impl PinkNoise {
+	pub fn new(duration: f64, start_offset: f64, amplitude: f64) -> Self {
+		return Self {
+			duration,
+			start_offset,
+			amplitude,
+			seed: None,
+		};
+	}
+}
+
+/// Integrated white noise with power falling by 6 dB per octave
+#[derive(Clone)]
+pub struct BrownianNoise {
+	/// Duration in s
+	duration: f64,
+	/// Starting time in s relative to global starting point
+	start_offset: f64,
+	/// Dimensionless amplitude, values stay between -amplitude and amplitude
+	amplitude: f64,
+	/// Seed of random values, None draws new values on every evaluation
+	seed: Option<u64>,
+}
+
+impl CalculableSignal for BrownianNoise {
+	fn calculate_signal(&self, sampling_points: &[f64]) -> Vec<crate::CoordPair> {
+		// random walk reflected at the bounds, which keeps the 6 dB per octave slope above the lowest frequencies
+		let mut rng = sequence_rng(self.seed);
+		let mut value = 0.0;
+		return sampling_points.iter().map(|point| {
+			let step: f64 = rand_distr::StandardNormal.sample(&mut rng);
+			value += BROWNIAN_NOISE_STEP * step;
+			if value.abs() > 1.0 {
+				value = (2.0 - value.abs()).copysign(value);
+			}
+			return crate::CoordPair {
+				x: *point,
+				y: self.amplitude * value,
+			};
+		}).collect();
+	}
+	fn get_signal_start(&self) -> f64 {
+		return self.start_offset;
+	}
+	fn get_signal_end(&self) -> f64 {
+		return self.start_offset + self.duration;
+	}
+	fn get_parameters(&self) -> Vec<(&'static str, f64)> {
+		return vec![("duration", self.duration), ("start_offset", self.start_offset), ("amplitude", self.amplitude)];
+	}
+	fn set_parameter(&mut self, name: &str, value: f64) -> bool {
+		match name {
+			"duration" => self.duration = value,
+			"start_offset" => self.start_offset = value,
+			"amplitude" => self.amplitude = value,
+			_ => return false,
+		}
+		return true;
+	}
+	fn clone_box(&self) -> Box<dyn CalculableSignal> {
+		return Box::new(self.clone());
+	}
+	fn get_type_name(&self) -> &'static str {
+		return "brownian_noise";
+	}
+	fn with_seed(&self, seed: u64) -> Option<Box<dyn CalculableSignal>> {
+		return Some(Box::new(Self {
+			seed: Some(seed),
+			..self.clone()
+		}));
+	}
+	fn is_pointwise(&self) -> bool {
+		return false;
+	}
+}
+
+impl BrownianNoise {
+	pub fn new(duration: f64, start_offset: f64, amplitude: f64) -> Self {
+		return Self {
+			duration,
+			start_offset,
+			amplitude,
+			seed: None,
+		};
+	}
+}
+
+#[derive(Clone)]
+pub struct ImpulsiveNoise {
+	/// Probability of a spike at every sample. Between 0 and 1
+	probability: f64,
+	/// Height of positive spikes
+	positive_amplitude: f64,
+	/// Depth of negative spikes, given as a positive number
+	negative_amplitude: f64,
+	/// Duration in s
+	duration: f64,
+	/// Starting time in s relative to global starting point
+	start_offset: f64,
+	/// Seed of random values, None draws new values on every evaluation
+	seed: Option<u64>,
+}
+
+impl CalculableSignal for ImpulsiveNoise {
+	fn calculate_signal(&self, sampling_points: &[f64]) -> Vec<crate::CoordPair> {
+		// a single draw decides both if there is a spike and its sign, each sign gets half of the probability
+		return sampling_points.iter().zip(random_values(sampling_points, self.seed, rand::distributions::Uniform::new(0.0, 1.0))).map(|(point, draw)| {
+			return crate::CoordPair {
+				x: *point,
+				y: if draw < 0.5 * self.probability { self.positive_amplitude } else if draw < self.probability { -self.negative_amplitude } else { 0.0 },
+			};
+		}).collect();
+	}
+	fn get_signal_start(&self) -> f64 {
+		return self.start_offset;
+	}
+	fn get_signal_end(&self) -> f64 {
+		return self.start_offset + self.duration;
+	}
+	fn get_parameters(&self) -> Vec<(&'static str, f64)> {
+		return vec![("probability", self.probability), ("positive_amplitude", self.positive_amplitude), ("negative_amplitude", self.negative_amplitude), ("duration", self.duration), ("start_offset", self.start_offset)];
+	}
+	fn set_parameter(&mut self, name: &str, value: f64) -> bool {
+		match name {
+			"probability" => self.probability = value,
+			"positive_amplitude" => self.positive_amplitude = value,
+			"negative_amplitude" => self.negative_amplitude = value,
+			"duration" => self.duration = value,
+			"start_offset" => self.start_offset = value,
+			_ => return false,
+		}
+		return true;
+	}
+	fn clone_box(&self) -> Box<dyn CalculableSignal> {
+		return Box::new(self.clone());
+	}
+	fn get_type_name(&self) -> &'static str {
+		return "impulsive_noise";
+	}
+	fn with_seed(&self, seed: u64) -> Option<Box<dyn CalculableSignal>> {
+		return Some(Box::new(Self {
+			seed: Some(seed),
+			..self.clone()
+		}));
+	}
+}
+
+impl ImpulsiveNoise {
+	pub fn new(probability: f64, positive_amplitude: f64, negative_amplitude: f64, duration: f64, start_offset: f64) -> Self {
+		return Self {
+			probability,
+			positive_amplitude,
+			negative_amplitude,
+			duration,
+			start_offset,
+			seed: None,
+		};
+	}
+}
+
+#[derive(Clone)]
+pub struct ExpressionSignal {
+	/// Formula of time `t` in s relative to global starting point, as accepted by `expression::parse`
+	text: String,
+	expression: crate::expression::Expression,
+	/// Duration in s
+	duration: f64,
+	/// Starting time in s relative to global starting point
+	start_offset: f64,
+}
+
+impl CalculableSignal for ExpressionSignal {
+	fn calculate_signal(&self, sampling_points: &[f64]) -> Vec<crate::CoordPair> {
+		return sampling_points.iter().map(|point| {
+			return crate::CoordPair {
+				x: *point,
+				y: self.expression.evaluate(*point),
+			};
+		}).collect();
+	}
+	fn get_signal_start(&self) -> f64 {
+		return self.start_offset;
+	}
+	fn get_signal_end(&self) -> f64 {
+		return self.start_offset + self.duration;
+	}
+	fn get_parameters(&self) -> Vec<(&'static str, f64)> {
+		return vec![("duration", self.duration), ("start_offset", self.start_offset)];
+	}
+	fn set_parameter(&mut self, name: &str, value: f64) -> bool {
+		match name {
+			"duration" => self.duration = value,
+			"start_offset" => self.start_offset = value,
+			_ => return false,
+		}
+		return true;
+	}
+	fn clone_box(&self) -> Box<dyn CalculableSignal> {
+		return Box::new(self.clone());
+	}
+	fn get_type_name(&self) -> &'static str {
+		return "expression";
+	}
+	fn get_text(&self) -> Option<&str> {
+		return Some(&self.text);
+	}
+}
+
+impl ExpressionSignal {
+	/// Fails with a description of the problem when `text` isn't a valid expression
+	pub fn new(text: String, duration: f64, start_offset: f64) -> Result<Self, String> {
+		return Ok(Self {
+			expression: crate::expression::parse(&text)?,
+			text,
+			duration,
+			start_offset,
+		});
+	}
+}
+
+#[derive(Clone)]
+pub struct SampledSignal {
+	/// Sampling frequency of stored samples in Hz
+	sampling_frequency: f64,
+	/// Time of the first sample in s
+	start_offset: f64,
+	/// Values of the signal, evenly spaced starting at `start_offset`
+	samples: Vec<f64>,
+	/// How values between samples are found, one of the methods of `resampling::resample`
+	interpolation: String,
+}
+
+impl CalculableSignal for SampledSignal {
+	fn calculate_signal(&self, sampling_points: &[f64]) -> Vec<crate::CoordPair> {
+		let values = crate::resampling::resample(&self.samples, self.start_offset, self.sampling_frequency, sampling_points, &self.interpolation);
+		return sampling_points.iter().zip(values).map(|(point, value)| {
+			return crate::CoordPair {
+				x: *point,
+				y: value,
+			};
+		}).collect();
+	}
+	fn get_signal_start(&self) -> f64 {
+		return self.start_offset;
+	}
+	fn get_signal_end(&self) -> f64 {
+		return self.start_offset + self.samples.len() as f64 / self.sampling_frequency;
+	}
+	fn get_parameters(&self) -> Vec<(&'static str, f64)> {
+		return vec![("sampling_frequency", self.sampling_frequency), ("start_offset", self.start_offset)];
+	}
+	fn set_parameter(&mut self, name: &str, value: f64) -> bool {
+		match name {
+			"sampling_frequency" => self.sampling_frequency = value,
+			"start_offset" => self.start_offset = value,
+			_ => return false,
+		}
+		return true;
+	}
+	fn clone_box(&self) -> Box<dyn CalculableSignal> {
+		return Box::new(self.clone());
+	}
+	fn get_type_name(&self) -> &'static str {
+		return "sampled";
+	}
+	fn get_samples(&self) -> Option<&[f64]> {
+		return Some(&self.samples);
+	}
+	fn get_text(&self) -> Option<&str> {
+		return Some(&self.interpolation);
+	}
+}
+
+impl SampledSignal {
+	/// `interpolation` has to be accepted by `resampling::is_method`
+	pub fn new(sampling_frequency: f64, start_offset: f64, samples: Vec<f64>, interpolation: String) -> Self {
+		return Self {
+			sampling_frequency,
+			start_offset,
+			samples,
+			interpolation,
+		};
+	}
+}
+
+#[derive(Clone)]
+pub struct MorseSignal {
+	/// Keyed text, characters without a Morse code are skipped
+	text: String,
+	/// Speed in words per minute, using the 50 unit word PARIS
+	words_per_minute: f64,
+	/// Frequency of the keyed tone in Hz
+	tone_freq: f64,
+	/// Starting time in s relative to global starting point
+	start_offset: f64,
+	/// Dimensionless amplitude
+	amplitude: f64,
+}
+
+impl CalculableSignal for MorseSignal {
+	fn calculate_signal(&self, sampling_points: &[f64]) -> Vec<crate::CoordPair> {
+		let (key_down_intervals, _) = self.get_key_down_intervals();
+		return sampling_points.iter().map(|point| {
+			let local_time = point - self.start_offset;
+			let key_down = key_down_intervals.iter().any(|(start, end)| local_time >= *start && local_time < *end);
+			return crate::CoordPair {
+				x: *point,
+				y: if key_down { self.amplitude * (self.tone_freq * TAU * point).sin() } else { 0.0 },
+			};
+		}).collect();
+	}
+	fn get_signal_start(&self) -> f64 {
+		return self.start_offset;
+	}
+	fn get_signal_end(&self) -> f64 {
+		return self.start_offset + self.get_key_down_intervals().1;
+	}
+	fn get_parameters(&self) -> Vec<(&'static str, f64)> {
+		return vec![("words_per_minute", self.words_per_minute), ("tone_freq", self.tone_freq), ("start_offset", self.start_offset), ("amplitude", self.amplitude)];
+	}
+	fn set_parameter(&mut self, name: &str, value: f64) -> bool {
+		match name {
+			"words_per_minute" => self.words_per_minute = value,
+			"tone_freq" => self.tone_freq = value,
+			"start_offset" => self.start_offset = value,
+			"amplitude" => self.amplitude = value,
+			_ => return false,
+		}
+		return true;
+	}
+	fn clone_box(&self) -> Box<dyn CalculableSignal> {
+		return Box::new(self.clone());
+	}
+	fn get_type_name(&self) -> &'static str {
+		return "morse";
+	}
+	fn get_text(&self) -> Option<&str> {
+		return Some(&self.text);
+	}
+}
+
+impl MorseSignal {
+	pub fn new(text: String, words_per_minute: f64, tone_freq: f64, start_offset: f64, amplitude: f64) -> Self {
+		return Self {
+			text,
+			words_per_minute,
+			tone_freq,
+			start_offset,
+			amplitude,
+		};
+	}
+
+	/// Times in s relative to signal start where the key is down, and the total duration
+	fn get_key_down_intervals(&self) -> (Vec<(f64, f64)>, f64) {
+		// dot is one unit, dash three, gaps within a character one unit, between characters three and between words seven
+		let unit = 1.2 / self.words_per_minute;
+		let mut intervals = Vec::new();
+		let mut time = 0.0;
+		for word in self.text.split_whitespace() {
+			if !intervals.is_empty() {
+				time += 4.0 * unit;
+			}
+			for code in word.chars().filter_map(morse_code) {
+				if !intervals.is_empty() {
+					time += 2.0 * unit;
+				}
+				for element in code.chars() {
+					let length = if element == '-' { 3.0 * unit } else { unit };
+					intervals.push((time, time + length));
+					// one unit gap after every element, extended between characters and words
+					time += length + unit;
+				}
+			}
+		}
+		let duration = intervals.last().map(|(_, end)| *end).unwrap_or(0.0);
+		return (intervals, duration);
+	}
+}
+
+/// International Morse code of `character` as dots and dashes
+fn morse_code(character: char) -> Option<&'static str> {
+	return Some(match character.to_ascii_uppercase() {
+		'A' => ".-", 'B' => "-...", 'C' => "-.-.", 'D' => "-..", 'E' => ".", 'F' => "..-.",
+		'G' => "--.", 'H' => "....", 'I' => "..", 'J' => ".---", 'K' => "-.-", 'L' => ".-..",
+		'M' => "--", 'N' => "-.", 'O' => "---", 'P' => ".--.", 'Q' => "--.-", 'R' => ".-.",
+		'S' => "...", 'T' => "-", 'U' => "..-", 'V' => "...-", 'W' => ".--", 'X' => "-..-",
+		'Y' => "-.--", 'Z' => "--..",
+		'0' => "-----", '1' => ".----", '2' => "..---", '3' => "...--", '4' => "....-",
+		'5' => ".....", '6' => "-....", '7' => "--...", '8' => "---..", '9' => "----.",
+		'.' => ".-.-.-", ',' => "--..--", '?' => "..--..", '/' => "-..-.", '=' => "-...-",
+		_ => return None,
+	});
+}
+
+/// Result of an arithmetic operation on two signals, each of them 0 outside of its own duration
+#[derive(Clone)]
+pub struct CompositeSignal {
+	/// "add", "subtract", "multiply" or "divide"
+	operation: String,
+	left: Box<dyn CalculableSignal>,
+	right: Box<dyn CalculableSignal>,
+}
+
+impl CalculableSignal for CompositeSignal {
+	fn calculate_signal(&self, sampling_points: &[f64]) -> Vec<crate::CoordPair> {
+		let evaluate = |signal: &dyn CalculableSignal| signal.calculate_signal(sampling_points).into_iter().map(|point| {
+			return if point.x >= signal.get_signal_start() && point.x < signal.get_signal_end() { point.y } else { 0.0 };
+		}).collect::<Vec<f64>>();
+		return sampling_points.iter().zip(evaluate(self.left.as_ref())).zip(evaluate(self.right.as_ref())).map(|((point, left), right)| {
+			return crate::CoordPair {
+				x: *point,
+				y: apply_operation(&self.operation, left, right).unwrap(),
+			};
+		}).collect();
+	}
+	fn get_signal_start(&self) -> f64 {
+		return self.left.get_signal_start().min(self.right.get_signal_start());
+	}
+	fn get_signal_end(&self) -> f64 {
+		return self.left.get_signal_end().max(self.right.get_signal_end());
+	}
+	fn get_parameters(&self) -> Vec<(&'static str, f64)> {
+		return Vec::new();
+	}
+	fn set_parameter(&mut self, _name: &str, _value: f64) -> bool {
+		return false;
+	}
+	fn clone_box(&self) -> Box<dyn CalculableSignal> {
+		return Box::new(self.clone());
+	}
+	fn get_type_name(&self) -> &'static str {
+		return "composite";
+	}
+	fn get_text(&self) -> Option<&str> {
+		return Some(&self.operation);
+	}
+	fn get_operands(&self) -> Vec<&dyn CalculableSignal> {
+		return vec![self.left.as_ref(), self.right.as_ref()];
+	}
+	fn with_seed(&self, seed: u64) -> Option<Box<dyn CalculableSignal>> {
+		let left = self.left.with_seed(derive_seed(seed, 0));
+		let right = self.right.with_seed(derive_seed(seed, 1));
+		if left.is_none() && right.is_none() {
+			return None;
+		}
+		return Some(Box::new(Self::new(self.operation.clone(), left.unwrap_or_else(|| self.left.clone()), right.unwrap_or_else(|| self.right.clone()))));
+	}
+	fn is_pointwise(&self) -> bool {
+		return self.left.is_pointwise() && self.right.is_pointwise();
+	}
+}
+
+impl CompositeSignal {
+	/// `operation` has to be one accepted by `apply_operation`
+	pub fn new(operation: String, left: Box<dyn CalculableSignal>, right: Box<dyn CalculableSignal>) -> Self {
+		return Self {
+			operation,
+			left,
+			right,
 		};
 	}
 }
\ No newline at end of file
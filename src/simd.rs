@@ -0,0 +1,128 @@
+// Batch versions of the per-sample loops of `signals`, used with the "simd" feature
+// The loops call nothing from libm, so the compiler is able to vectorize them into f64x2 operations when built with
+// `-C target-feature=+simd128` for WASM, and into SSE/AVX natively. Their only conditions pick between two computed
+// values, which can become blends, but whether they do is up to the compiler. `benches/waveforms.rs` compares both paths
+
+use std::f64::consts::PI;
+
+/// Difference between π and the f64 closest to it
+const PI_LOW: f64 = 1.2246467991473532e-16;
+/// 2^27 + 1, splits an f64 into two halves whose products are exact
+const SPLITTER: f64 = 134217729.0;
+/// 1 / (2k + 1)! with alternating signs, the Taylor series of sine up to x^23, exact to f64 precision within -π/2..π/2
+const SINE_COEFFICIENTS: [f64; 12] = [
+	1.0,
+	-1.0 / 6.0,
+	1.0 / 120.0,
+	-1.0 / 5040.0,
+	1.0 / 362880.0,
+	-1.0 / 39916800.0,
+	1.0 / 6227020800.0,
+	-1.0 / 1307674368000.0,
+	1.0 / 355687428096000.0,
+	-1.0 / 121645100408832000.0,
+	1.0 / 51090942171709440000.0,
+	-1.0 / 25852016738884976640000.0,
+];
+
+/// sin(x) without a libm call, so a loop of it can be vectorized
+#[inline(always)]
+fn sine(x: f64) -> f64 {
+	let turns = (x / PI).round();
+	let (multiple, error) = exact_product(turns, PI);
+	let reduced = ((x - multiple) - error) - turns * PI_LOW;
+	let squared = reduced * reduced;
+	let series = SINE_COEFFICIENTS.iter().rev().fold(0.0, |sum, coefficient| sum * squared + coefficient);
+	// sin(x) = (-1)^k sin(x - kπ), picked arithmetically instead of with a branch
+	let sign = 1.0 - 4.0 * (turns * 0.5 - (turns * 0.5).floor());
+	return sign * reduced * series;
+}
+
+/// `a` * `b` as the rounded product and its rounding error, which add up to the exact product (Dekker's algorithm)
+#[inline(always)]
+fn exact_product(a: f64, b: f64) -> (f64, f64) {
+	let split = |value: f64| {
+		let scaled = SPLITTER * value;
+		let high = scaled - (scaled - value);
+		return (high, value - high);
+	};
+	let product = a * b;
+	let ((a_high, a_low), (b_high, b_low)) = (split(a), split(b));
+	return (product, ((a_high * b_high - product) + a_high * b_low + a_low * b_high) + a_low * b_low);
+}
+
+/// Remainder of `x / period` with the sign of `x`, like `%`, without the fmod call
+#[inline(always)]
+fn remainder(x: f64, period: f64) -> f64 {
+	let magnitude = x.abs();
+	let (multiple, error) = exact_product((magnitude / period).trunc(), period);
+	// the remainder is representable, so subtracting the exact multiple of the period gives it without rounding
+	let remainder = (magnitude - multiple) - error;
+	// next to multiples of the period the quotient can round across an integer, which pushes the remainder out of 0..period
+	let remainder = if remainder < 0.0 { remainder + period } else if remainder >= period { remainder - period } else { remainder };
+	return remainder.copysign(x);
+}
+
+/// `amplitude` * sin(`angular_frequency` * t + `phase_shift`) at every point t
+pub fn sine_wave(points: &[f64], angular_frequency: f64, phase_shift: f64, amplitude: f64) -> Vec<f64> {
+	return points.iter().map(|point| amplitude * sine(angular_frequency * point + phase_shift)).collect();
+}
+
+/// `amplitude` up to `flip_point` of every `period`, -`amplitude` after it
+pub fn rectangular_wave(points: &[f64], period: f64, flip_point: f64, amplitude: f64) -> Vec<f64> {
+	return points.iter().map(|point| {
+		let high = (remainder(*point, period) <= flip_point) as u8 as f64;
+		return amplitude * (2.0 * high - 1.0);
+	}).collect();
+}
+
+/// Falls from `amplitude` to 0 until `flip_point` of every `period` and rises back to `amplitude` after it
+pub fn triangular_wave(points: &[f64], period: f64, flip_point: f64, amplitude: f64) -> Vec<f64> {
+	return points.iter().map(|point| {
+		let offset = remainder(*point, period);
+		let rising = (offset - flip_point) / (period - flip_point);
+		let falling = 1.0 - offset / flip_point;
+		return amplitude * if offset > flip_point { rising } else { falling };
+	}).collect();
+}
+
+
+#[cfg(test)]
+mod tests {
+	use std::f64::consts::TAU;
+
+	use super::*;
+
+	/// Points around zero and exact multiples of `period`, where the remainder is the most fragile
+	fn points(period: f64) -> Vec<f64> {
+		return (-20000..20000).map(|index| index as f64 * 0.0731).chain((-2000..2000).map(|multiple| multiple as f64 * period)).collect();
+	}
+
+	#[test]
+	fn sine_matches_scalar() {
+		let points = points(0.02);
+		for (value, point) in sine_wave(&points, 50.0 * TAU, 0.4, 1.5).into_iter().zip(&points) {
+			assert!((value - 1.5 * (50.0 * TAU * point + 0.4).sin()).abs() < 1e-14, "at {}", point);
+		}
+	}
+
+	#[test]
+	fn rectangular_matches_scalar() {
+		let (period, flip_point) = (0.02, 0.006);
+		let points = points(period);
+		for (value, point) in rectangular_wave(&points, period, flip_point, 2.0).into_iter().zip(&points) {
+			assert_eq!(value, if point % period > flip_point { -2.0 } else { 2.0 }, "at {}", point);
+		}
+	}
+
+	#[test]
+	fn triangular_matches_scalar() {
+		let (period, flip_point) = (0.02, 0.006);
+		let points = points(period);
+		for (value, point) in triangular_wave(&points, period, flip_point, 2.0).into_iter().zip(&points) {
+			let offset = point % period;
+			let expected = if offset > flip_point { (offset - flip_point) / (period - flip_point) } else { 1.0 - offset / flip_point };
+			assert_eq!(value, 2.0 * expected, "at {}", point);
+		}
+	}
+}
\ No newline at end of file
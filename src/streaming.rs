@@ -0,0 +1,110 @@
+use std::f64::consts::TAU;
+
+use rand::{distributions::Distribution, rngs::StdRng, SeedableRng};
+
+use crate::{api_error, ApiError};
+#[cfg(feature = "wasm")]
+use wasm_bindgen::prelude::*;
+
+struct Oscillator {
+	/// "sine", "square", "triangle", "sawtooth", "uniform_noise" or "normal_noise"
+	waveform: String,
+	/// Frequency in Hz used for the current sample
+	frequency: f64,
+	/// Frequency in Hz the oscillator glides towards
+	target_frequency: f64,
+	/// Frequency change per sample while gliding
+	frequency_step: f64,
+	/// Dimensionless amplitude used for the current sample
+	amplitude: f64,
+	/// Amplitude reached by the end of the next buffer
+	target_amplitude: f64,
+	/// Position within the period, between 0 and 1
+	phase: f64,
+}
+
+/// Renders consecutive buffers of a bank of oscillators for real time output
+/// Phase and noise generator state carry over between buffers, so changing parameters doesn't cause clicks
+#[cfg_attr(feature = "wasm", wasm_bindgen)]
+pub struct StreamingGenerator {
+	/// Sampling frequency in Hz
+	sampling_frequency: f64,
+	oscillators: Vec<Oscillator>,
+	rng: StdRng,
+}
+
+#[cfg_attr(feature = "wasm", wasm_bindgen)]
+impl StreamingGenerator {
+	pub fn new(sampling_frequency: f64) -> Self {
+		return Self {
+			sampling_frequency,
+			oscillators: Vec::new(),
+			rng: StdRng::from_entropy(),
+		};
+	}
+
+	/// Adds an oscillator of `waveform` ("sine", "square", "triangle", "sawtooth", "uniform_noise" or "normal_noise")
+	/// Returns its index. Frequency is ignored by noise waveforms
+	pub fn add_oscillator(&mut self, waveform: &str, frequency: f64, amplitude: f64) -> Result<usize, ApiError> {
+		if !matches!(waveform, "sine" | "square" | "triangle" | "sawtooth" | "uniform_noise" | "normal_noise") {
+			return Err(api_error(&format!("Unknown waveform {}", waveform)));
+		}
+		self.oscillators.push(Oscillator {
+			waveform: waveform.to_string(),
+			frequency,
+			target_frequency: frequency,
+			frequency_step: 0.0,
+			amplitude,
+			target_amplitude: amplitude,
+			phase: 0.0,
+		});
+		return Ok(self.oscillators.len() - 1);
+	}
+
+	/// Slides frequency of oscillator at `index` to `frequency` Hz over `glide_seconds`, 0 changes it immediately
+	pub fn set_frequency(&mut self, index: usize, frequency: f64, glide_seconds: f64) -> Result<(), ApiError> {
+		let oscillator = self.oscillators.get_mut(index).ok_or_else(|| api_error(&format!("No oscillator at index {}", index)))?;
+		let glide_samples = (glide_seconds * self.sampling_frequency).max(1.0);
+		oscillator.target_frequency = frequency;
+		oscillator.frequency_step = (frequency - oscillator.frequency) / glide_samples;
+		return Ok(());
+	}
+
+	/// Changes amplitude of oscillator at `index`, ramped over the next buffer
+	pub fn set_amplitude(&mut self, index: usize, amplitude: f64) -> Result<(), ApiError> {
+		self.oscillators.get_mut(index).ok_or_else(|| api_error(&format!("No oscillator at index {}", index)))?.target_amplitude = amplitude;
+		return Ok(());
+	}
+
+	/// Renders next `length` samples of all oscillators summed together
+	pub fn next_buffer(&mut self, length: usize) -> Vec<f64> {
+		let mut buffer = vec![0.0; length];
+		let uniform = rand::distributions::Uniform::new_inclusive(-1.0, 1.0);
+		for oscillator in &mut self.oscillators {
+			let amplitude_step = (oscillator.target_amplitude - oscillator.amplitude) / length.max(1) as f64;
+			for sample in buffer.iter_mut() {
+				let unit_value = match oscillator.waveform.as_str() {
+					"sine" => (TAU * oscillator.phase).sin(),
+					"square" => if oscillator.phase < 0.5 { 1.0 } else { -1.0 },
+					"triangle" => 1.0 - 4.0 * (oscillator.phase - 0.5).abs(),
+					"sawtooth" => 2.0 * oscillator.phase - 1.0,
+					"uniform_noise" => uniform.sample(&mut self.rng),
+					_ => rand_distr::StandardNormal.sample(&mut self.rng),
+				};
+				*sample += oscillator.amplitude * unit_value;
+
+				oscillator.amplitude += amplitude_step;
+				oscillator.phase = (oscillator.phase + oscillator.frequency / self.sampling_frequency).rem_euclid(1.0);
+				if oscillator.frequency != oscillator.target_frequency {
+					oscillator.frequency += oscillator.frequency_step;
+					// overshooting the target ends the glide
+					if (oscillator.target_frequency - oscillator.frequency) * oscillator.frequency_step <= 0.0 {
+						oscillator.frequency = oscillator.target_frequency;
+					}
+				}
+			}
+			oscillator.amplitude = oscillator.target_amplitude;
+		}
+		return buffer;
+	}
+}
\ No newline at end of file
@@ -0,0 +1,38 @@
+// Native threads only, the module isn't compiled for wasm32 even with the threads feature
+// A browser worker pool would need wasm-bindgen-rayon and a build with atomics, which this crate doesn't have
+
+use std::thread;
+
+/// Pieces shorter than this are not worth a thread of their own
+const MINIMUM_PIECE_LENGTH: usize = 65536;
+
+/// Number of threads work is spread over, 1 when the system can't tell
+fn thread_count() -> usize {
+	return thread::available_parallelism().map(|count| count.get()).unwrap_or(1);
+}
+
+/// `function` applied to every item, in the order of `items`, with consecutive items split between threads
+/// Panics inside `function` are passed on unchanged
+pub fn map_parallel<T: Sync, R: Send>(items: &[T], function: impl Fn(&T) -> R + Sync) -> Vec<R> {
+	let threads = thread_count().min(items.len());
+	if threads < 2 {
+		return items.iter().map(&function).collect();
+	}
+	let function = &function;
+	return thread::scope(|scope| {
+		let handles: Vec<_> = items.chunks(items.len().div_ceil(threads)).map(|group| scope.spawn(move || group.iter().map(function).collect::<Vec<R>>())).collect();
+		return handles.into_iter().flat_map(|handle| handle.join().unwrap_or_else(|panic| std::panic::resume_unwind(panic))).collect();
+	});
+}
+
+/// `evaluate` of `points` calculated in pieces on separate threads and joined back together
+/// Only valid when the value at a point depends on nothing but the point
+pub fn evaluate_split<T: Send + Copy>(points: &[f64], evaluate: impl Fn(&[f64]) -> Vec<T> + Sync) -> Vec<T> {
+	let pieces = thread_count().min(points.len() / MINIMUM_PIECE_LENGTH);
+	if pieces < 2 {
+		return evaluate(points);
+	}
+	let piece_length = points.len().div_ceil(pieces);
+	let pieces: Vec<&[f64]> = points.chunks(piece_length).collect();
+	return map_parallel(&pieces, |piece| evaluate(piece)).concat();
+}
\ No newline at end of file
@@ -0,0 +1,371 @@
+use std::f64::consts::TAU;
+
+#[cfg(feature = "wasm")]
+use wasm_bindgen::prelude::*;
+
+use crate::complex::Complex;
+use crate::progress::CANCELLED;
+use crate::windows;
+use crate::{api_error, ApiError};
+
+#[cfg_attr(feature = "wasm", wasm_bindgen)]
+pub struct PeakEstimate {
+	/// Frequency in Hz
+	pub frequency: f64,
+	/// Amplitude of a sine that would produce this peak
+	pub amplitude: f64,
+}
+
+/// One-sided spectrum from DC to Nyquist frequency
+#[cfg_attr(feature = "wasm", wasm_bindgen)]
+pub struct Spectrum {
+	frequencies: Vec<f64>,
+	values: Vec<Complex>,
+}
+
+#[cfg_attr(feature = "wasm", wasm_bindgen)]
+impl Spectrum {
+	/// Frequency of every bin in Hz
+	pub fn frequencies(&self) -> Vec<f64> {
+		return self.frequencies.clone();
+	}
+
+	/// Amplitude of the sine at every bin frequency, a sine lying exactly on a bin reads its own amplitude
+	pub fn magnitudes(&self) -> Vec<f64> {
+		return self.values.iter().map(Complex::abs).collect();
+	}
+
+	/// Magnitudes in dB relative to a sine of amplitude 1, empty bins are -inf
+	pub fn magnitudes_db(&self) -> Vec<f64> {
+		return self.magnitudes().into_iter().map(|magnitude| 20.0 * magnitude.log10()).collect();
+	}
+
+	/// Phase of the cosine at every bin frequency in radians, between -π and π
+	pub fn phases(&self) -> Vec<f64> {
+		return self.values.iter().map(Complex::arg).collect();
+	}
+
+	/// Phases without the jumps of 2π between neighbouring bins, continuous as in a Bode plot
+	pub fn unwrapped_phases(&self) -> Vec<f64> {
+		return unwrap_phase(&self.phases());
+	}
+}
+
+impl Spectrum {
+	/// Spectrum of complex `values` at `frequencies` in Hz
+	pub fn new(frequencies: Vec<f64>, values: Vec<Complex>) -> Self {
+		return Self {
+			frequencies,
+			values,
+		};
+	}
+}
+
+/// Adds multiples of 2π to `phases` in radians so no two neighbours differ by more than π
+pub fn unwrap_phase(phases: &[f64]) -> Vec<f64> {
+	let mut offset = 0.0;
+	let mut unwrapped: Vec<f64> = Vec::with_capacity(phases.len());
+	for (index, phase) in phases.iter().enumerate() {
+		if index > 0 {
+			let step = phase - phases[index - 1];
+			offset -= TAU * (step / TAU).round();
+		}
+		unwrapped.push(phase + offset);
+	}
+	return unwrapped;
+}
+
+/// Magnitudes of short-time spectra, one frame after another
+#[cfg_attr(feature = "wasm", wasm_bindgen)]
+pub struct Spectrogram {
+	magnitudes: Vec<f64>,
+	times: Vec<f64>,
+	frequencies: Vec<f64>,
+}
+
+#[cfg_attr(feature = "wasm", wasm_bindgen)]
+impl Spectrogram {
+	/// Amplitudes of all bins of the first frame, then all bins of the next one and so on
+	/// Value at `frame` and `bin` is at index `frame * bin_count() + bin`
+	pub fn magnitudes(&self) -> Vec<f64> {
+		return self.magnitudes.clone();
+	}
+
+	/// Time in s of the center of every frame
+	pub fn times(&self) -> Vec<f64> {
+		return self.times.clone();
+	}
+
+	/// Frequency in Hz of every bin, from DC to Nyquist frequency
+	pub fn frequencies(&self) -> Vec<f64> {
+		return self.frequencies.clone();
+	}
+
+	pub fn frame_count(&self) -> usize {
+		return self.times.len();
+	}
+
+	pub fn bin_count(&self) -> usize {
+		return self.frequencies.len();
+	}
+}
+
+/// Short-time Fourier transform of `samples` taken at `sampling_frequency` starting at `start_time` s
+/// Frames as long as `window` start every `hop_size` samples and are zero-padded to a power of two
+/// Magnitudes are scaled so a sine reads its amplitude whatever the window
+pub fn spectrogram(samples: &[f64], start_time: f64, sampling_frequency: f64, window: &[f64], hop_size: usize) -> Spectrogram {
+	let window_sum: f64 = window.iter().sum();
+	let transform_length = window.len().next_power_of_two();
+	let bin_count = transform_length / 2 + 1;
+	let mut magnitudes = Vec::new();
+	let mut times = Vec::new();
+	let mut frame_start = 0;
+	while frame_start + window.len() <= samples.len() {
+		let frame: Vec<f64> = samples[frame_start..frame_start + window.len()].iter().zip(window).map(|(sample, weight)| sample * weight).collect();
+		let transform = fft_real(&frame);
+		magnitudes.extend(transform.iter().take(bin_count).enumerate().map(|(bin, value)| {
+			let scale = if bin == 0 || 2 * bin == transform_length { 1.0 } else { 2.0 };
+			return value.abs() * scale / window_sum;
+		}));
+		times.push(start_time + (frame_start as f64 + 0.5 * window.len() as f64) / sampling_frequency);
+		frame_start += hop_size;
+	}
+	return Spectrogram {
+		magnitudes,
+		times,
+		frequencies: fft_frequencies(transform_length, sampling_frequency, true),
+	};
+}
+
+/// One-sided spectrum of real `samples` taken at `sampling_frequency`, zero-padded to the next power of two
+/// Bins are scaled by the number of samples, doubling all but DC and Nyquist to account for negative frequencies
+pub fn spectrum(samples: &[f64], sampling_frequency: f64) -> Spectrum {
+	return windowed_spectrum(samples, sampling_frequency, &vec![1.0; samples.len()]);
+}
+
+/// Same as `spectrum`, but `samples` are first multiplied by `window` of the same length
+/// Bins are scaled by the sum of the window instead, so a sine lying on a bin still reads its amplitude
+pub fn windowed_spectrum(samples: &[f64], sampling_frequency: f64, window: &[f64]) -> Spectrum {
+	return windowed_spectrum_reporting(samples, sampling_frequency, window, &mut |_| true).unwrap_or_else(|_| Spectrum::new(Vec::new(), Vec::new()));
+}
+
+/// `windowed_spectrum` reporting progress of its FFT like `fft_in_place_reporting`
+pub fn windowed_spectrum_reporting(samples: &[f64], sampling_frequency: f64, window: &[f64], progress: &mut dyn FnMut(f64) -> bool) -> Result<Spectrum, String> {
+	let windowed: Vec<f64> = samples.iter().zip(window).map(|(sample, weight)| sample * weight).collect();
+	let window_sum: f64 = window.iter().sum();
+	let transform = fft_real_reporting(&windowed, progress)?;
+	let bin_count = transform.len() / 2 + 1;
+	let values = transform.iter().take(bin_count).enumerate().map(|(bin, value)| {
+		let scale = if bin == 0 || 2 * bin == transform.len() { 1.0 } else { 2.0 };
+		return value.scale(scale / window_sum);
+	}).collect();
+	return Ok(Spectrum {
+		frequencies: fft_frequencies(transform.len(), sampling_frequency, true),
+		values,
+	});
+}
+
+/// Real samples of a signal `length` samples long, whose `spectrum` would consist of `values`
+/// `values` are bins from DC to Nyquist frequency of a power of two FFT, so there have to be 2^k + 1 of them
+/// Imaginary parts of DC and Nyquist bins are ignored, as a real signal can't have them
+pub fn inverse_spectrum(values: &[Complex], length: usize) -> Result<Vec<f64>, String> {
+	let transform_length = 2 * values.len().saturating_sub(1);
+	if transform_length < 2 || !transform_length.is_power_of_two() {
+		return Err(format!("Spectrum needs 2^k + 1 bins, got {}", values.len()));
+	}
+	if length > transform_length {
+		return Err(format!("Spectrum of {} bins can't describe {} samples", values.len(), length));
+	}
+	let mut full_spectrum = vec![Complex::ZERO; transform_length];
+	for (bin, value) in values.iter().enumerate() {
+		let is_real_bin = bin == 0 || bin == transform_length / 2;
+		let unscaled = value.scale(length as f64 / if is_real_bin { 1.0 } else { 2.0 });
+		if is_real_bin {
+			full_spectrum[bin] = Complex::new(unscaled.re, 0.0);
+		} else {
+			// spectrum of a real signal is conjugate symmetric
+			full_spectrum[bin] = unscaled;
+			full_spectrum[transform_length - bin] = unscaled.conj();
+		}
+	}
+	fft_in_place(&mut full_spectrum, true);
+	return Ok(full_spectrum.into_iter().take(length).map(|value| value.re).collect());
+}
+
+/// Frequencies in Hz of bins of an `n` point FFT at sampling frequency `fs`
+/// One-sided axis covers bins 0..=n/2 (DC to Nyquist), two-sided follows FFT output order - non-negative frequencies first, then negative ones
+#[cfg_attr(feature = "wasm", wasm_bindgen)]
+pub fn fft_frequencies(n: usize, fs: f64, one_sided: bool) -> Vec<f64> {
+	if one_sided {
+		return (0..=n / 2).map(|bin| bin_to_frequency(bin as f64, n, fs)).collect();
+	}
+	return (0..n).map(|bin| {
+		// bins past the middle hold negative frequencies
+		let signed_bin = if bin < n.div_ceil(2) { bin as f64 } else { bin as f64 - n as f64 };
+		return bin_to_frequency(signed_bin, n, fs);
+	}).collect();
+}
+
+/// Frequency in Hz of (possibly fractional) `bin` of an `n` point FFT at sampling frequency `fs`
+#[cfg_attr(feature = "wasm", wasm_bindgen)]
+pub fn bin_to_frequency(bin: f64, n: usize, fs: f64) -> f64 {
+	return bin * fs / n as f64;
+}
+
+/// Index of the bin of an `n` point FFT at sampling frequency `fs` closest to `frequency` in Hz
+/// Negative frequencies map to bins in the upper half, as in FFT output order. Fails for an FFT without points
+#[cfg_attr(feature = "wasm", wasm_bindgen)]
+pub fn frequency_to_bin(frequency: f64, n: usize, fs: f64) -> Result<usize, ApiError> {
+	if n == 0 {
+		return Err(api_error("FFT needs at least one point"));
+	}
+	let bin = (frequency * n as f64 / fs).round() as i64;
+	return Ok(bin.rem_euclid(n as i64) as usize);
+}
+
+/// Iterative radix-2 Cooley-Tukey FFT, done in place. Length of `values` needs to be a power of two
+/// Inverse transform is scaled by 1/N, so transforming forward and back returns the original values
+pub fn fft_in_place(values: &mut [Complex], inverse: bool) {
+	let _ = fft_in_place_reporting(values, inverse, &mut |_| true);
+}
+
+/// `fft_in_place` calling `progress` with the fraction of butterfly stages done
+/// Stops with an error, leaving `values` partly transformed, once `progress` returns false
+pub fn fft_in_place_reporting(values: &mut [Complex], inverse: bool, progress: &mut dyn FnMut(f64) -> bool) -> Result<(), String> {
+	let length = values.len();
+	if length < 2 {
+		return Ok(());
+	}
+	// bit reversal permutation, so butterflies can work on neighbouring blocks
+	let bits = length.trailing_zeros();
+	for index in 0..length {
+		let reversed = index.reverse_bits() >> (usize::BITS - bits);
+		if reversed > index {
+			values.swap(index, reversed);
+		}
+	}
+	let direction = if inverse { 1.0 } else { -1.0 };
+	let mut block_size = 2;
+	while block_size <= length {
+		let angle_step = direction * TAU / block_size as f64;
+		for block_start in (0..length).step_by(block_size) {
+			for offset in 0..block_size / 2 {
+				let twiddle = Complex::from_polar(1.0, angle_step * offset as f64);
+				let even = values[block_start + offset];
+				let odd = values[block_start + offset + block_size / 2] * twiddle;
+				values[block_start + offset] = even + odd;
+				values[block_start + offset + block_size / 2] = even - odd;
+			}
+		}
+		if !progress(block_size.trailing_zeros() as f64 / bits as f64) {
+			return Err(CANCELLED.to_string());
+		}
+		block_size *= 2;
+	}
+	if inverse {
+		for value in values.iter_mut() {
+			*value = value.scale(1.0 / length as f64);
+		}
+	}
+	return Ok(());
+}
+
+/// Spectrum of real `samples` zero-padded to the next power of two
+pub fn fft_real(samples: &[f64]) -> Vec<Complex> {
+	return fft_real_reporting(samples, &mut |_| true).unwrap_or_default();
+}
+
+/// `fft_real` reporting progress like `fft_in_place_reporting`
+pub fn fft_real_reporting(samples: &[f64], progress: &mut dyn FnMut(f64) -> bool) -> Result<Vec<Complex>, String> {
+	let mut values: Vec<Complex> = samples.iter().map(|sample| Complex::new(*sample, 0.0)).collect();
+	values.resize(samples.len().next_power_of_two(), Complex::ZERO);
+	fft_in_place_reporting(&mut values, false, progress)?;
+	return Ok(values);
+}
+
+/// Full linear convolution of `a` and `b` computed by multiplying their spectra, length is a.len() + b.len() - 1
+pub fn fft_convolve(a: &[f64], b: &[f64]) -> Vec<f64> {
+	return fft_convolve_reporting(a, b, &mut |_| true).unwrap_or_default();
+}
+
+/// `fft_convolve` reporting progress of its three FFTs like `fft_in_place_reporting`
+pub fn fft_convolve_reporting(a: &[f64], b: &[f64], progress: &mut dyn FnMut(f64) -> bool) -> Result<Vec<f64>, String> {
+	if a.is_empty() || b.is_empty() {
+		return Ok(Vec::new());
+	}
+	let result_length = a.len() + b.len() - 1;
+	// padding to the full result length turns circular convolution into linear one
+	let padded_length = result_length.next_power_of_two();
+	let mut to_complex = |values: &[f64], transform: f64| {
+		let mut complex_values: Vec<Complex> = values.iter().map(|value| Complex::new(*value, 0.0)).collect();
+		complex_values.resize(padded_length, Complex::ZERO);
+		fft_in_place_reporting(&mut complex_values, false, &mut |fraction| progress((transform + fraction) / 3.0))?;
+		return Ok::<_, String>(complex_values);
+	};
+	let spectrum_a = to_complex(a, 0.0)?;
+	let spectrum_b = to_complex(b, 1.0)?;
+	let mut product: Vec<Complex> = spectrum_a.into_iter().zip(spectrum_b).map(|(x, y)| x * y).collect();
+	fft_in_place_reporting(&mut product, true, &mut |fraction| progress((2.0 + fraction) / 3.0))?;
+	return Ok(product.into_iter().take(result_length).map(|value| value.re).collect());
+}
+
+/// Power of a single DFT bin at `frequency` Hz computed with the Goertzel algorithm
+/// Sine of amplitude A at that frequency gives (A * N / 2)^2 for N samples
+pub fn goertzel_power(samples: &[f64], frequency: f64, sampling_frequency: f64) -> f64 {
+	let coefficient = 2.0 * (TAU * frequency / sampling_frequency).cos();
+	let mut previous = 0.0;
+	let mut before_previous = 0.0;
+	for sample in samples {
+		let current = sample + coefficient * previous - before_previous;
+		before_previous = previous;
+		previous = current;
+	}
+	return previous * previous + before_previous * before_previous - coefficient * previous * before_previous;
+}
+
+/// Frequency and amplitude of the strongest spectral peak, refined by parabolic interpolation of neighbouring bins
+/// DC bin is skipped. Returns None when the spectrum is too short to have a peak
+pub fn estimate_peak(samples: &[f64], sampling_frequency: f64) -> Option<PeakEstimate> {
+	// Hann window keeps the main lobe close to a parabola and leakage from other components low
+	let windowed: Vec<f64> = samples.iter().zip(windows::hann(samples.len())).map(|(sample, weight)| sample * weight).collect();
+	let spectrum = fft_real(&windowed);
+	let padded_length = spectrum.len();
+	if padded_length < 4 {
+		return None;
+	}
+	let magnitudes: Vec<f64> = spectrum[..=padded_length / 2].iter().map(|value| value.abs()).collect();
+	let peak_bin = (1..magnitudes.len()).max_by(|x, y| magnitudes[*x].total_cmp(&magnitudes[*y])).unwrap();
+	// sine of amplitude A gives a peak of A * N / 2 for N samples, halved by the window
+	let amplitude_scale = 4.0 / samples.len() as f64;
+	if peak_bin + 1 >= magnitudes.len() || magnitudes[peak_bin - 1] <= 0.0 || magnitudes[peak_bin + 1] <= 0.0 {
+		return Some(PeakEstimate {
+			frequency: bin_to_frequency(peak_bin as f64, padded_length, sampling_frequency),
+			amplitude: magnitudes[peak_bin] * amplitude_scale,
+		});
+	}
+	// parabola fitted to logarithmic magnitudes matches the shape of the main lobe better than linear ones
+	let left = magnitudes[peak_bin - 1].ln();
+	let center = magnitudes[peak_bin].ln();
+	let right = magnitudes[peak_bin + 1].ln();
+	let curvature = left - 2.0 * center + right;
+	let bin_offset = if curvature == 0.0 { 0.0 } else { 0.5 * (left - right) / curvature };
+	let peak_log_magnitude = center - 0.25 * (left - right) * bin_offset;
+	return Some(PeakEstimate {
+		frequency: bin_to_frequency(peak_bin as f64 + bin_offset, padded_length, sampling_frequency),
+		amplitude: peak_log_magnitude.exp() * amplitude_scale,
+	});
+}
+
+#[cfg(test)]
+mod tests {
+	use super::*;
+
+	#[test]
+	fn inverse_fft_returns_the_input() {
+		let input: Vec<Complex> = (0..64).map(|index| Complex::new((index as f64 * 0.7).sin(), (index as f64 * 0.3).cos())).collect();
+		let mut values = input.clone();
+		fft_in_place(&mut values, false);
+		fft_in_place(&mut values, true);
+		assert!(values.iter().zip(&input).all(|(value, original)| (*value - *original).abs() < 1e-12));
+	}
+}
\ No newline at end of file
@@ -0,0 +1,113 @@
+/// Mono RIFF/WAVE file of `samples` at `sampling_frequency` rounded to whole Hz
+/// `bit_depth` 8, 16 or 24 stores integer PCM with samples clipped to -1..1, 32 stores IEEE floats as they are
+pub fn encode(samples: &[f64], sampling_frequency: f64, bit_depth: u16) -> Result<Vec<u8>, String> {
+	let format: u16 = match bit_depth {
+		8 | 16 | 24 => 1,
+		32 => 3,
+		_ => return Err(format!("Unsupported bit depth {}, use 8, 16, 24 or 32", bit_depth)),
+	};
+	let sample_rate = sampling_frequency.round() as u32;
+	let block_align = bit_depth / 8;
+	let data_size = samples.len() as u32 * block_align as u32;
+
+	let mut bytes = Vec::with_capacity(44 + data_size as usize);
+	bytes.extend_from_slice(b"RIFF");
+	bytes.extend_from_slice(&(36 + data_size).to_le_bytes());
+	bytes.extend_from_slice(b"WAVE");
+	bytes.extend_from_slice(b"fmt ");
+	bytes.extend_from_slice(&16_u32.to_le_bytes());
+	bytes.extend_from_slice(&format.to_le_bytes());
+	bytes.extend_from_slice(&1_u16.to_le_bytes()); // channels
+	bytes.extend_from_slice(&sample_rate.to_le_bytes());
+	bytes.extend_from_slice(&(sample_rate * block_align as u32).to_le_bytes());
+	bytes.extend_from_slice(&block_align.to_le_bytes());
+	bytes.extend_from_slice(&bit_depth.to_le_bytes());
+	bytes.extend_from_slice(b"data");
+	bytes.extend_from_slice(&data_size.to_le_bytes());
+	for sample in samples {
+		let clipped = sample.clamp(-1.0, 1.0);
+		match bit_depth {
+			// 8-bit PCM is unsigned with silence at 128
+			8 => bytes.push((clipped * 128.0 + 128.0).round().min(255.0) as u8),
+			16 => bytes.extend_from_slice(&((clipped * i16::MAX as f64).round() as i16).to_le_bytes()),
+			24 => bytes.extend_from_slice(&((clipped * 8_388_607.0).round() as i32).to_le_bytes()[..3]),
+			_ => bytes.extend_from_slice(&(*sample as f32).to_le_bytes()),
+		}
+	}
+	if data_size % 2 == 1 {
+		// chunks are padded to an even length
+		bytes.push(0);
+	}
+	return Ok(bytes);
+}
+
+/// Samples of a RIFF/WAVE file with their sampling frequency in Hz, channels mixed down to mono by averaging
+/// Reads integer PCM of 8, 16, 24 or 32 bits scaled to -1..1 and 32 or 64-bit floats, also in the extensible format
+pub fn decode(bytes: &[u8]) -> Result<(Vec<f64>, f64), String> {
+	if bytes.len() < 12 || &bytes[0..4] != b"RIFF" || &bytes[8..12] != b"WAVE" {
+		return Err("Not a RIFF/WAVE file".to_string());
+	}
+	let mut format = None;
+	let mut data = None;
+	let mut position = 12;
+	while position + 8 <= bytes.len() {
+		let size = u32::from_le_bytes(bytes[position + 4..position + 8].try_into().unwrap()) as usize;
+		let body = &bytes[position + 8..(position + 8 + size).min(bytes.len())];
+		match &bytes[position..position + 4] {
+			b"fmt " => format = Some(body),
+			b"data" => data = Some(body),
+			_ => {}
+		}
+		// chunks are padded to an even length
+		position += 8 + size + size % 2;
+	}
+	let format = format.filter(|format| format.len() >= 16).ok_or("WAV file has no format chunk")?;
+	let data = data.ok_or("WAV file has no data chunk")?;
+	let read_u16 = |offset: usize| u16::from_le_bytes([format[offset], format[offset + 1]]);
+	let mut format_tag = read_u16(0);
+	let channels = read_u16(2) as usize;
+	let sample_rate = u32::from_le_bytes(format[4..8].try_into().unwrap());
+	let bits = read_u16(14) as usize;
+	if format_tag == 0xFFFE && format.len() >= 26 {
+		// extensible format keeps the actual format in the first two bytes of its subformat GUID
+		format_tag = read_u16(24);
+	}
+	if channels == 0 || sample_rate == 0 {
+		return Err("WAV file has no channels or no sample rate".to_string());
+	}
+	let decode_sample: fn(&[u8]) -> f64 = match (format_tag, bits) {
+		(1, 8) => |bytes| (bytes[0] as f64 - 128.0) / 128.0,
+		(1, 16) => |bytes| i16::from_le_bytes([bytes[0], bytes[1]]) as f64 / 32768.0,
+		// the 24-bit value goes to the top of an i32, so its sign is kept
+		(1, 24) => |bytes| (i32::from_le_bytes([0, bytes[0], bytes[1], bytes[2]]) >> 8) as f64 / 8_388_608.0,
+		(1, 32) => |bytes| i32::from_le_bytes(bytes.try_into().unwrap()) as f64 / 2_147_483_648.0,
+		(3, 32) => |bytes| f32::from_le_bytes(bytes.try_into().unwrap()) as f64,
+		(3, 64) => |bytes| f64::from_le_bytes(bytes.try_into().unwrap()),
+		_ => return Err(format!("Unsupported WAV format {} with {} bits", format_tag, bits)),
+	};
+	let frame_size = channels * bits / 8;
+	let samples = data.chunks_exact(frame_size).map(|frame| {
+		return frame.chunks_exact(bits / 8).map(decode_sample).sum::<f64>() / channels as f64;
+	}).collect();
+	return Ok((samples, sample_rate as f64));
+}
+
+#[cfg(test)]
+mod tests {
+	use super::*;
+
+	#[test]
+	fn round_trip() {
+		let samples: Vec<f64> = (0..101).map(|index| (index as f64 * 0.37).sin() * 0.9).collect();
+		let (decoded, sampling_frequency) = decode(&encode(&samples, 44100.0, 32).unwrap()).unwrap();
+		assert_eq!(sampling_frequency, 44100.0);
+		assert_eq!(decoded, samples.iter().map(|sample| *sample as f32 as f64).collect::<Vec<f64>>());
+		for bit_depth in [8, 16, 24] {
+			let (decoded, _) = decode(&encode(&samples, 8000.0, bit_depth).unwrap()).unwrap();
+			assert_eq!(decoded.len(), samples.len());
+			// encoding scales by the largest code and decoding by the one past it, each can be off by a step
+			let step = 2.0_f64.powi(2 - bit_depth as i32);
+			assert!(decoded.iter().zip(&samples).all(|(decoded, sample)| (decoded - sample).abs() <= step), "{} bits", bit_depth);
+		}
+	}
+}
\ No newline at end of file
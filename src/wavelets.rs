@@ -0,0 +1,232 @@
+#[cfg(feature = "wasm")]
+use wasm_bindgen::prelude::*;
+
+use crate::{api_error, ApiError};
+
+/// Wavelet coefficients of a signal split into `levels` octave bands
+pub struct Decomposition {
+	/// Coarsest approximation left after the last level
+	pub approximation: Vec<f64>,
+	/// Detail coefficients of every level, starting from the finest one
+	pub details: Vec<Vec<f64>>,
+	/// Number of samples of the decomposed signal before padding
+	pub length: usize,
+}
+
+/// Lowpass decomposition filter of an orthogonal `wavelet`, "haar" or "db4" for Daubechies with 4 coefficients
+pub fn scaling_filter(wavelet: &str) -> Option<Vec<f64>> {
+	return match wavelet {
+		"haar" => Some(vec![std::f64::consts::FRAC_1_SQRT_2; 2]),
+		"db4" => {
+			let root = 3.0_f64.sqrt();
+			let scale = 4.0 * std::f64::consts::SQRT_2;
+			Some(vec![(1.0 + root) / scale, (3.0 + root) / scale, (3.0 - root) / scale, (1.0 - root) / scale])
+		}
+		_ => None,
+	};
+}
+
+/// Highpass filter mirroring lowpass `scaling`
+fn wavelet_filter(scaling: &[f64]) -> Vec<f64> {
+	return scaling.iter().rev().enumerate().map(|(index, coefficient)| if index % 2 == 0 { *coefficient } else { -coefficient }).collect();
+}
+
+/// Multi-level discrete wavelet transform of `samples` with lowpass filter `scaling`, treating the signal as periodic
+/// Samples are zero padded to a multiple of 2^`levels`
+pub fn decompose(samples: &[f64], levels: usize, scaling: &[f64]) -> Decomposition {
+	let block = 1 << levels;
+	let mut approximation = samples.to_vec();
+	approximation.resize(samples.len().div_ceil(block) * block, 0.0);
+	let wavelet = wavelet_filter(scaling);
+	let mut details = Vec::with_capacity(levels);
+	for _ in 0..levels {
+		let length = approximation.len();
+		let analyze = |filter: &[f64]| -> Vec<f64> {
+			return (0..length / 2).map(|index| filter.iter().enumerate().map(|(tap, coefficient)| coefficient * approximation[(2 * index + tap) % length]).sum()).collect();
+		};
+		let detail = analyze(&wavelet);
+		approximation = analyze(scaling);
+		details.push(detail);
+	}
+	return Decomposition {
+		approximation,
+		details,
+		length: samples.len(),
+	};
+}
+
+/// Inverse of `decompose`, returns as many samples as were decomposed
+pub fn reconstruct(decomposition: &Decomposition, scaling: &[f64]) -> Vec<f64> {
+	let wavelet = wavelet_filter(scaling);
+	let mut approximation = decomposition.approximation.clone();
+	for detail in decomposition.details.iter().rev() {
+		let length = 2 * approximation.len();
+		let mut finer = vec![0.0; length];
+		for (index, (coarse, fine)) in approximation.iter().zip(detail).enumerate() {
+			for (tap, (low, high)) in scaling.iter().zip(&wavelet).enumerate() {
+				finer[(2 * index + tap) % length] += low * coarse + high * fine;
+			}
+		}
+		approximation = finer;
+	}
+	approximation.truncate(decomposition.length);
+	return approximation;
+}
+
+/// Discrete wavelet transform of a signal, coefficients can be edited before transforming back
+#[cfg_attr(feature = "wasm", wasm_bindgen)]
+pub struct WaveletTransform {
+	decomposition: Decomposition,
+	scaling: Vec<f64>,
+}
+
+#[cfg_attr(feature = "wasm", wasm_bindgen)]
+impl WaveletTransform {
+	/// Number of decomposition levels
+	pub fn levels(&self) -> usize {
+		return self.decomposition.details.len();
+	}
+
+	/// Coarsest approximation coefficients left after the last level
+	pub fn approximation(&self) -> Vec<f64> {
+		return self.decomposition.approximation.clone();
+	}
+
+	/// Detail coefficients of `level`, 0 being the finest one with the upper half of the band
+	pub fn details(&self, level: usize) -> Result<Vec<f64>, ApiError> {
+		return Ok(self.level(level)?.clone());
+	}
+
+	/// Replaces coefficients of the approximation, `values` must have the same length
+	pub fn set_approximation(&mut self, values: Vec<f64>) -> Result<(), ApiError> {
+		if values.len() != self.decomposition.approximation.len() {
+			return Err(api_error(&format!("Approximation has {} coefficients, got {}", self.decomposition.approximation.len(), values.len())));
+		}
+		self.decomposition.approximation = values;
+		return Ok(());
+	}
+
+	/// Replaces detail coefficients of `level`, `values` must have the same length
+	pub fn set_details(&mut self, level: usize, values: Vec<f64>) -> Result<(), ApiError> {
+		let details = self.level(level)?.len();
+		if values.len() != details {
+			return Err(api_error(&format!("Level {} has {} coefficients, got {}", level, details, values.len())));
+		}
+		self.decomposition.details[level] = values;
+		return Ok(());
+	}
+
+	fn level(&self, level: usize) -> Result<&Vec<f64>, ApiError> {
+		return self.decomposition.details.get(level).ok_or_else(|| api_error(&format!("Transform has {} levels, got level {}", self.levels(), level)));
+	}
+
+	/// Signal reconstructed from the coefficients, as many samples as were transformed
+	pub fn inverse(&self) -> Vec<f64> {
+		return reconstruct(&self.decomposition, &self.scaling);
+	}
+}
+
+/// Transforms `samples` over `levels` levels with `wavelet`, one of the wavelets of `scaling_filter`
+pub fn transform(samples: &[f64], levels: usize, wavelet: &str) -> Result<WaveletTransform, String> {
+	let scaling = scaling_filter(wavelet).ok_or(format!("Unknown wavelet {}", wavelet))?;
+	return Ok(WaveletTransform {
+		decomposition: decompose(samples, levels, &scaling),
+		scaling,
+	});
+}
+
+#[cfg_attr(feature = "wasm", wasm_bindgen)]
+pub struct WaveletDenoising {
+	values: Vec<f64>,
+	/// Estimated SNR in dB of the noisy signal
+	pub input_snr_db: f64,
+	/// Estimated SNR in dB after denoising, assuming every kept coefficient still carries noise
+	pub output_snr_db: f64,
+}
+
+#[cfg_attr(feature = "wasm", wasm_bindgen)]
+impl WaveletDenoising {
+	/// Denoised signal at the sampling points of the original one
+	pub fn values(&self) -> Vec<f64> {
+		return self.values.clone();
+	}
+}
+
+/// Removes white noise from `samples` by thresholding their db4 detail coefficients over `levels` levels
+/// `threshold_rule` is "universal" for σ√(2 ln N) or "sure" for Stein's unbiased risk estimate chosen separately for every level
+/// `thresholding` is "hard", which zeroes small coefficients, or "soft", which also shrinks the rest towards zero
+/// Noise level σ is estimated from the median absolute value of the finest details
+pub fn denoise(samples: &[f64], levels: usize, threshold_rule: &str, thresholding: &str) -> Result<WaveletDenoising, String> {
+	if !matches!(thresholding, "hard" | "soft") {
+		return Err(format!("Unknown thresholding {}", thresholding));
+	}
+	if samples.is_empty() {
+		return Err("No samples to denoise".to_string());
+	}
+	let scaling = scaling_filter("db4").unwrap();
+	let mut decomposition = decompose(samples, levels.max(1), &scaling);
+	let mut finest: Vec<f64> = decomposition.details[0].iter().map(|coefficient| coefficient.abs()).collect();
+	finest.sort_by(f64::total_cmp);
+	let noise_deviation = finest[finest.len() / 2] / 0.6745;
+	let universal = noise_deviation * (2.0 * (samples.len() as f64).ln()).sqrt();
+
+	let mut kept = decomposition.approximation.len();
+	for detail in &mut decomposition.details {
+		let threshold = match threshold_rule {
+			"universal" => universal,
+			"sure" => sure_threshold(detail, noise_deviation).min(universal),
+			_ => return Err(format!("Unknown threshold rule {}", threshold_rule)),
+		};
+		for coefficient in detail.iter_mut() {
+			if coefficient.abs() <= threshold {
+				*coefficient = 0.0;
+			} else {
+				kept += 1;
+				if thresholding == "soft" {
+					*coefficient -= threshold.copysign(*coefficient);
+				}
+			}
+		}
+	}
+
+	let values = reconstruct(&decomposition, &scaling);
+	let signal_power = values.iter().map(|value| value * value).sum::<f64>() / values.len() as f64;
+	let noise_power = noise_deviation * noise_deviation;
+	let padded_length = decomposition.approximation.len() << decomposition.details.len();
+	return Ok(WaveletDenoising {
+		values,
+		input_snr_db: 10.0 * (signal_power / noise_power).log10(),
+		output_snr_db: 10.0 * (signal_power / (noise_power * kept as f64 / padded_length as f64)).log10(),
+	});
+}
+
+/// Soft threshold minimizing Stein's unbiased estimate of the risk for `coefficients` with white noise of `noise_deviation`
+fn sure_threshold(coefficients: &[f64], noise_deviation: f64) -> f64 {
+	if noise_deviation == 0.0 {
+		return 0.0;
+	}
+	let mut squares: Vec<f64> = coefficients.iter().map(|coefficient| (coefficient / noise_deviation).powi(2)).collect();
+	squares.sort_by(f64::total_cmp);
+	let count = squares.len() as f64;
+	let mut best = (f64::INFINITY, 0.0);
+	let mut sum_below = 0.0;
+	for (index, square) in squares.iter().enumerate() {
+		sum_below += square;
+		let above = count - index as f64 - 1.0;
+		let risk = count - 2.0 * (index as f64 + 1.0) + sum_below + above * square;
+		if risk < best.0 {
+			best = (risk, *square);
+		}
+	}
+	return noise_deviation * best.1.sqrt();
+}
+
+#[cfg(test)]
+mod tests {
+	use super::*;
+
+	#[test]
+	fn denoising_no_samples_fails() {
+		assert!(denoise(&[], 3, "universal", "hard").is_err());
+	}
+}
\ No newline at end of file
@@ -0,0 +1,73 @@
+use std::f64::consts::TAU;
+
+/// β of the Kaiser window picked by name, which makes it similar to the Blackman-Harris window
+const DEFAULT_KAISER_BETA: f64 = 8.6;
+
+/// Window of `length` samples of kind `name`: "rectangular", "hann", "hamming", "blackman", "bartlett" or "kaiser"
+/// None for unknown kinds
+pub fn create(name: &str, length: usize) -> Option<Vec<f64>> {
+	return match name {
+		"rectangular" => Some(vec![1.0; length]),
+		"hann" => Some(hann(length)),
+		"hamming" => Some(cosine_sum(length, &[0.54, 0.46])),
+		"blackman" => Some(blackman(length)),
+		"bartlett" => Some(bartlett(length)),
+		"kaiser" => Some(kaiser(length, DEFAULT_KAISER_BETA)),
+		_ => None,
+	};
+}
+
+/// Symmetric Hann window of `length` points
+pub fn hann(length: usize) -> Vec<f64> {
+	return cosine_sum(length, &[0.5, 0.5]);
+}
+
+/// Symmetric Blackman window of `length` points
+pub fn blackman(length: usize) -> Vec<f64> {
+	return cosine_sum(length, &[0.42, 0.5, 0.08]);
+}
+
+/// Symmetric triangular window of `length` points, zero at both ends
+pub fn bartlett(length: usize) -> Vec<f64> {
+	let half_span = length.saturating_sub(1).max(1) as f64 / 2.0;
+	return (0..length).map(|index| 1.0 - ((index as f64 - half_span) / half_span).abs()).collect();
+}
+
+/// Symmetric Kaiser window of `length` points, larger `beta` trades a wider main lobe for lower sidelobes
+pub fn kaiser(length: usize, beta: f64) -> Vec<f64> {
+	let half_span = length.saturating_sub(1).max(1) as f64 / 2.0;
+	return (0..length).map(|index| kaiser_at((index as f64 - half_span) / half_span, beta)).collect();
+}
+
+/// Kaiser window at `position` between -1 and 1 from its center to its ends, 0 outside
+pub fn kaiser_at(position: f64, beta: f64) -> f64 {
+	if position.abs() > 1.0 {
+		return 0.0;
+	}
+	return bessel_i0(beta * (1.0 - position * position).sqrt()) / bessel_i0(beta);
+}
+
+/// Generalized cosine window a0 - a1 cos(x) + a2 cos(2x) - ... with alternating signs of `coefficients`
+fn cosine_sum(length: usize, coefficients: &[f64]) -> Vec<f64> {
+	let last_index = length.saturating_sub(1).max(1) as f64;
+	return (0..length).map(|index| {
+		let phase = TAU * index as f64 / last_index;
+		return coefficients.iter().enumerate().map(|(order, coefficient)| {
+			let sign = if order % 2 == 0 { 1.0 } else { -1.0 };
+			return sign * coefficient * (order as f64 * phase).cos();
+		}).sum();
+	}).collect();
+}
+
+/// Modified Bessel function of the first kind of order 0, from its power series
+fn bessel_i0(x: f64) -> f64 {
+	let mut sum = 1.0;
+	let mut term = 1.0;
+	let mut order = 1.0;
+	while term > sum * 1e-16 {
+		term *= (x / (2.0 * order)).powi(2);
+		sum += term;
+		order += 1.0;
+	}
+	return sum;
+}
\ No newline at end of file